@@ -0,0 +1,300 @@
+//! CHANGELOG.md in the Keep a Changelog format, built from the git history.
+//!
+//! Every tag starts a release, named after the tag without a leading `v`;
+//! commits after the newest tag are Unreleased. Only commits that follow the
+//! Conventional Commits format make it in, grouped by type: `feat` under
+//! Added, `fix` under Fixed, `perf` and `refactor` under Changed, `revert`
+//! under Removed, plus `deprecate` and `security`. Chores such as `docs`,
+//! `test`, `ci` or `build` are left out.
+
+use std::path::Path;
+use std::process::Command;
+
+/// The Keep a Changelog groups, in the order they are listed.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Group {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+}
+
+impl Group {
+    pub const ALL: [Group; 6] = [
+        Group::Added,
+        Group::Changed,
+        Group::Deprecated,
+        Group::Removed,
+        Group::Fixed,
+        Group::Security,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Group::Added => "Added",
+            Group::Changed => "Changed",
+            Group::Deprecated => "Deprecated",
+            Group::Removed => "Removed",
+            Group::Fixed => "Fixed",
+            Group::Security => "Security",
+        }
+    }
+
+    fn from_type(kind: &str) -> Option<Group> {
+        Some(match kind {
+            "feat" => Group::Added,
+            "perf" | "refactor" => Group::Changed,
+            "deprecate" => Group::Deprecated,
+            "revert" | "remove" => Group::Removed,
+            "fix" => Group::Fixed,
+            "security" => Group::Security,
+            _ => return None,
+        })
+    }
+}
+
+pub struct Release {
+    /// The tag, or `None` for the commits after the newest tag.
+    pub tag: Option<String>,
+    /// Commit date of the tag, `YYYY-MM-DD`.
+    pub date: String,
+    pub entries: Vec<(Group, String)>,
+}
+
+impl Release {
+    /// The tag without a leading `v`, or "Unreleased".
+    pub fn version(&self) -> &str {
+        match &self.tag {
+            Some(tag) => tag.strip_prefix('v').unwrap_or(tag),
+            None => "Unreleased",
+        }
+    }
+}
+
+/// The releases in the git repository containing `dir`, newest first and
+/// always starting with Unreleased.
+pub fn read(dir: &Path) -> Result<Vec<Release>, String> {
+    // Unit and record separators keep subjects with any text intact
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["log", "--date=short", "--format=%ad%x1f%D%x1f%s%x1e"])
+        .output()
+        .map_err(|err| format!("git: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(parse_log(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_log(log: &str) -> Vec<Release> {
+    let mut releases = vec![Release {
+        tag: None,
+        date: String::new(),
+        entries: Vec::new(),
+    }];
+    for record in log.split('\x1e') {
+        let mut parts = record.trim_start_matches('\n').splitn(3, '\x1f');
+        let (Some(date), Some(refs), Some(subject)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let tag = refs
+            .split(", ")
+            .find_map(|name| name.strip_prefix("tag: "));
+        if let Some(tag) = tag {
+            releases.push(Release {
+                tag: Some(tag.to_string()),
+                date: date.to_string(),
+                entries: Vec::new(),
+            });
+        }
+        if let Some(entry) = parse_subject(subject.trim()) {
+            releases.last_mut().expect("unreleased").entries.push(entry);
+        }
+    }
+    releases
+}
+
+/// `type(scope)!: description` as a changelog entry, the scope in bold
+/// and breaking changes marked.
+fn parse_subject(subject: &str) -> Option<(Group, String)> {
+    let (head, description) = subject.split_once(": ")?;
+    let (head, breaking) = match head.strip_suffix('!') {
+        Some(head) => (head, true),
+        None => (head, false),
+    };
+    let (kind, scope) = match head.split_once('(') {
+        Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?)),
+        None => (head, None),
+    };
+    let group = Group::from_type(&kind.to_lowercase())?;
+    let mut entry = String::new();
+    if breaking {
+        entry.push_str("**BREAKING:** ");
+    }
+    if let Some(scope) = scope.filter(|scope| !scope.is_empty()) {
+        entry.push_str(&format!("**{scope}:** "));
+    }
+    entry.push_str(description.trim());
+    Some((group, entry))
+}
+
+/// The changelog's Markdown. With `repo` set, versions link to the GitHub
+/// comparison with the release before.
+pub fn render(releases: &[Release], repo: &str) -> String {
+    let mut out = String::from("# Changelog\n\n");
+    out.push_str(
+        "All notable changes to this project are documented in this file.\n\n\
+         The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n\
+         and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\n",
+    );
+    if releases.is_empty() {
+        out.push_str("\n## Unreleased\n");
+    }
+    for release in releases {
+        let version = if repo.is_empty() {
+            release.version().to_string()
+        } else {
+            format!("[{}]", release.version())
+        };
+        if release.tag.is_some() {
+            out.push_str(&format!("\n## {version} - {}\n", release.date));
+        } else {
+            out.push_str(&format!("\n## {version}\n"));
+        }
+        for group in Group::ALL {
+            let entries: Vec<&str> = release
+                .entries
+                .iter()
+                .filter(|(g, _)| *g == group)
+                .map(|(_, entry)| entry.as_str())
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n### {}\n\n", group.title()));
+            for entry in entries {
+                out.push_str(&format!("- {entry}\n"));
+            }
+        }
+    }
+
+    if !repo.is_empty() && !releases.is_empty() {
+        out.push('\n');
+        for (i, release) in releases.iter().enumerate() {
+            let head = release.tag.as_deref().unwrap_or("HEAD");
+            let url = match releases.get(i + 1).and_then(|previous| previous.tag.as_deref()) {
+                Some(previous) => format!("https://github.com/{repo}/compare/{previous}...{head}"),
+                None if release.tag.is_some() => format!("https://github.com/{repo}/releases/tag/{head}"),
+                None => format!("https://github.com/{repo}/commits/HEAD"),
+            };
+            out.push_str(&format!("[{}]: {url}\n", release.version()));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(subject: &str) -> Option<(&'static str, String)> {
+        parse_subject(subject).map(|(group, entry)| (group.title(), entry))
+    }
+
+    #[test]
+    fn subject_types() {
+        assert_eq!(entry("feat: add a flag"), Some(("Added", String::from("add a flag"))));
+        assert_eq!(entry("fix: crash on start"), Some(("Fixed", String::from("crash on start"))));
+        assert_eq!(entry("perf: faster"), Some(("Changed", String::from("faster"))));
+        assert_eq!(entry("Revert: old thing"), Some(("Removed", String::from("old thing"))));
+        assert_eq!(entry("docs: typo"), None);
+        assert_eq!(entry("Add a flag"), None);
+        assert_eq!(entry("feat:missing space"), None);
+    }
+
+    #[test]
+    fn subject_scope() {
+        assert_eq!(entry("feat(cli): add --quiet"), Some(("Added", String::from("**cli:** add --quiet"))));
+        assert_eq!(entry("fix(): empty scope"), Some(("Fixed", String::from("empty scope"))));
+        assert_eq!(entry("fix(cli: unclosed"), None);
+    }
+
+    #[test]
+    fn subject_breaking() {
+        assert_eq!(entry("feat!: new config"), Some(("Added", String::from("**BREAKING:** new config"))));
+        assert_eq!(
+            entry("refactor(api)!: rename run"),
+            Some(("Changed", String::from("**BREAKING:** **api:** rename run")))
+        );
+    }
+
+    /// Records as `git log` prints them with the format `read` asks for.
+    fn log(commits: &[(&str, &str, &str)]) -> String {
+        commits
+            .iter()
+            .map(|(date, refs, subject)| format!("{date}\x1f{refs}\x1f{subject}\x1e\n"))
+            .collect()
+    }
+
+    fn summary(releases: &[Release]) -> Vec<(String, String, Vec<String>)> {
+        releases
+            .iter()
+            .map(|release| {
+                let entries = release.entries.iter().map(|(_, entry)| entry.clone()).collect();
+                (release.version().to_string(), release.date.clone(), entries)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn log_releases() {
+        let releases = parse_log(&log(&[
+            ("2024-03-01", "HEAD -> main, origin/main", "feat: newest"),
+            ("2024-02-01", "tag: v1.1.0", "fix: second"),
+            ("2024-01-15", "", "chore: skipped"),
+            ("2024-01-10", "", "feat(ui): first"),
+            ("2024-01-01", "tag: v1.0.0", "feat: initial"),
+        ]));
+        assert_eq!(
+            summary(&releases),
+            [
+                (String::from("Unreleased"), String::new(), vec![String::from("newest")]),
+                (
+                    String::from("1.1.0"),
+                    String::from("2024-02-01"),
+                    vec![String::from("second"), String::from("**ui:** first")]
+                ),
+                (String::from("1.0.0"), String::from("2024-01-01"), vec![String::from("initial")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn log_commit_with_several_tags() {
+        // One release per commit, named after the first tag
+        let releases = parse_log(&log(&[
+            ("2024-02-01", "HEAD -> main, tag: v2.0.0, tag: latest", "feat!: redo"),
+            ("2024-01-01", "tag: v1.0.0", "feat: initial"),
+        ]));
+        let versions: Vec<&str> = releases.iter().map(Release::version).collect();
+        assert_eq!(versions, ["Unreleased", "2.0.0", "1.0.0"]);
+        assert!(releases[0].entries.is_empty());
+        assert_eq!(releases[1].entries[0].1, "**BREAKING:** redo");
+    }
+
+    #[test]
+    fn log_without_commits() {
+        let releases = parse_log("");
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].version(), "Unreleased");
+    }
+
+    #[test]
+    fn subjects_keep_separator_like_text() {
+        let releases = parse_log(&log(&[("2024-01-01", "", "fix: handle a: b, tag: c")]));
+        assert_eq!(releases[0].entries[0].1, "handle a: b, tag: c");
+    }
+}
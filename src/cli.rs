@@ -0,0 +1,143 @@
+use std::env;
+
+pub const USAGE: &str = "\
+Usage: readme_generator [OPTIONS]
+
+Options:
+  -o, --output <PATH>  Where to write the README (default: README.md).
+                       A directory gets README.md appended, '-' means stdout.
+      --stdout         Print the README to stdout instead of writing a file.
+                       The interface is drawn on stderr so the output can be
+                       piped or redirected.
+  -l, --lang <LANGS>   Comma separated output languages, e.g. en,zh-CN.
+                       The first is written to the output path, the others
+                       to README.<lang>.md next to it
+                       (available: en, es, de, fr, ja, zh-CN)
+      --community <FILES>
+                       Comma separated community files to write next to the
+                       README and link from it (available: contributing,
+                       code-of-conduct, security, github-templates,
+                       gitlab-templates)
+      --changelog      Add a Changelog section and write CHANGELOG.md from
+                       the git history and tags
+      --license <SPDX>
+                       License as an SPDX id or expression, e.g. MIT or
+                       'MIT OR Apache-2.0'
+  -p, --preset <ID>    Start from a project type preset and skip that step
+                       (available: generic, cli, rust-lib, library, web-app,
+                       api-service)
+  -c, --config <PATH>  Use this project config instead of looking for
+                       .readmegen.toml
+  -f, --force          Overwrite an existing file without asking
+      --fresh          Start over instead of resuming the saved session
+      --lint <FILE>    Lint a Markdown file and exit non-zero on problems
+      --check-links <FILE>
+                       Check that relative links, images and anchors in a
+                       Markdown file resolve, and exit non-zero if not
+  -h, --help           Print this help";
+
+pub struct Args {
+    pub output: Option<String>,
+    pub stdout: bool,
+    pub force: bool,
+    pub fresh: bool,
+    pub config: Option<String>,
+    pub preset: Option<String>,
+    pub lang: Option<String>,
+    pub community: Option<String>,
+    pub changelog: bool,
+    pub license: Option<String>,
+    pub lint: Option<String>,
+    pub check_links: Option<String>,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse() -> Result<Args, String> {
+        Args::parse_from(env::args().skip(1))
+    }
+
+    pub fn parse_from<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+        let mut parsed = Args {
+            output: None,
+            stdout: false,
+            force: false,
+            fresh: false,
+            config: None,
+            preset: None,
+            lang: None,
+            community: None,
+            changelog: false,
+            license: None,
+            lint: None,
+            check_links: None,
+            help: false,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-o" | "--output" => {
+                    parsed.output = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a path"))?,
+                    );
+                }
+                "--stdout" => parsed.stdout = true,
+                "-f" | "--force" => parsed.force = true,
+                "--fresh" => parsed.fresh = true,
+                "--changelog" => parsed.changelog = true,
+                "-c" | "--config" => {
+                    parsed.config = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a path"))?,
+                    );
+                }
+                "-p" | "--preset" => {
+                    parsed.preset = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a preset id"))?,
+                    );
+                }
+                "-l" | "--lang" => {
+                    parsed.lang = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a language list"))?,
+                    );
+                }
+                "--community" => {
+                    parsed.community = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a file list"))?,
+                    );
+                }
+                "--license" => {
+                    parsed.license = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a license"))?,
+                    );
+                }
+                "--lint" => {
+                    parsed.lint = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a path"))?,
+                    );
+                }
+                "--check-links" => {
+                    parsed.check_links = Some(
+                        args.next()
+                            .ok_or_else(|| format!("{arg} requires a path"))?,
+                    );
+                }
+                "-h" | "--help" => parsed.help = true,
+                other => {
+                    if let Some(path) = other.strip_prefix("--output=") {
+                        parsed.output = Some(path.to_string());
+                    } else {
+                        return Err(format!("unknown argument '{other}'"));
+                    }
+                }
+            }
+        }
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,453 @@
+//! Community health files written next to the README.
+//!
+//! CONTRIBUTING.md is built from the contributing and test fields,
+//! CODE_OF_CONDUCT.md is the Contributor Covenant 2.1 with the contact
+//! email filled in, and SECURITY.md says where to report vulnerabilities.
+//! The issue and pull request templates, for GitHub or GitLab, ask for what
+//! a bug report or a change needs, the test instructions included. All of
+//! them are written in English, the language of the Covenant text, whatever
+//! languages the README itself is in.
+
+use crate::field::Field;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CommunityFile {
+    Contributing,
+    CodeOfConduct,
+    Security,
+    GitHubTemplates,
+    GitLabTemplates,
+}
+
+impl CommunityFile {
+    pub const ALL: [CommunityFile; 5] = [
+        CommunityFile::Contributing,
+        CommunityFile::CodeOfConduct,
+        CommunityFile::Security,
+        CommunityFile::GitHubTemplates,
+        CommunityFile::GitLabTemplates,
+    ];
+
+    /// Name used in config files and on the command line.
+    pub fn id(self) -> &'static str {
+        match self {
+            CommunityFile::Contributing => "contributing",
+            CommunityFile::CodeOfConduct => "code-of-conduct",
+            CommunityFile::Security => "security",
+            CommunityFile::GitHubTemplates => "github-templates",
+            CommunityFile::GitLabTemplates => "gitlab-templates",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<CommunityFile> {
+        CommunityFile::ALL.into_iter().find(|file| file.id() == id)
+    }
+
+    /// The file the README links to; `None` for the templates, which the
+    /// forge offers by itself when an issue or pull request is opened.
+    pub fn file_name(self) -> Option<&'static str> {
+        match self {
+            CommunityFile::Contributing => Some("CONTRIBUTING.md"),
+            CommunityFile::CodeOfConduct => Some("CODE_OF_CONDUCT.md"),
+            CommunityFile::Security => Some("SECURITY.md"),
+            CommunityFile::GitHubTemplates | CommunityFile::GitLabTemplates => None,
+        }
+    }
+
+    /// Ids of the fields the file is rendered from.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            CommunityFile::Contributing => &["contributing", "tests"],
+            CommunityFile::CodeOfConduct | CommunityFile::Security => &["contact_email"],
+            CommunityFile::GitHubTemplates | CommunityFile::GitLabTemplates => &["tests"],
+        }
+    }
+
+    /// Paths, relative to the project directory, and Markdown of the files
+    /// written. `files` are all the files being written, so they can point
+    /// at each other.
+    pub fn render(self, fields: &[Field], files: &[CommunityFile]) -> Vec<(&'static str, String)> {
+        let value = |id: &str| {
+            fields
+                .iter()
+                .find(|field| field.id == id)
+                .map_or("", |field| field.value.trim())
+        };
+        let title = match value("title") {
+            "" => "this project",
+            title => title,
+        };
+        let repo = value("repository");
+        let email = value("contact_email");
+        let tests = value("tests");
+        let contributing_file = files.contains(&CommunityFile::Contributing);
+        match self {
+            CommunityFile::Contributing => vec![(
+                "CONTRIBUTING.md",
+                contributing(title, repo, value("contributing"), tests, files),
+            )],
+            CommunityFile::CodeOfConduct => {
+                vec![("CODE_OF_CONDUCT.md", CODE_OF_CONDUCT.replace("{contact}", email))]
+            }
+            CommunityFile::Security => vec![("SECURITY.md", security(title, repo, email, files))],
+            CommunityFile::GitHubTemplates => vec![
+                (
+                    ".github/ISSUE_TEMPLATE/bug_report.md",
+                    front_matter("Bug report", &format!("Report something in {title} that does not work"), "bug")
+                        + &bug_report(title),
+                ),
+                (
+                    ".github/ISSUE_TEMPLATE/feature_request.md",
+                    front_matter("Feature request", &format!("Suggest an idea for {title}"), "enhancement")
+                        + &feature_request(),
+                ),
+                (".github/pull_request_template.md", pull_request(tests, contributing_file)),
+            ],
+            // GitLab has no front matter; quick actions label the issue
+            CommunityFile::GitLabTemplates => vec![
+                (".gitlab/issue_templates/Bug.md", bug_report(title) + "\n/label ~bug\n"),
+                (".gitlab/issue_templates/Feature.md", feature_request() + "\n/label ~feature\n"),
+                (".gitlab/merge_request_templates/Default.md", pull_request(tests, contributing_file)),
+            ],
+        }
+    }
+}
+
+/// Whether the project is on GitLab: only the GitLab templates were chosen.
+/// Links to issues and advisories point at GitHub otherwise.
+pub fn on_gitlab(files: &[CommunityFile]) -> bool {
+    files.contains(&CommunityFile::GitLabTemplates) && !files.contains(&CommunityFile::GitHubTemplates)
+}
+
+/// The issue tracker of `repo`, an `owner/name` slug, on its forge.
+pub fn issues_url(repo: &str, files: &[CommunityFile]) -> String {
+    if on_gitlab(files) {
+        format!("https://gitlab.com/{repo}/-/issues")
+    } else {
+        format!("https://github.com/{repo}/issues")
+    }
+}
+
+/// Numbered steps from a `;` separated field, or a placeholder step.
+fn steps(value: &str, placeholder: &str) -> String {
+    if value.is_empty() {
+        return format!("1. {placeholder}\n");
+    }
+    value
+        .split(';')
+        .enumerate()
+        .map(|(i, step)| format!("{}. {}\n", i + 1, step.trim()))
+        .collect()
+}
+
+fn contributing(title: &str, repo: &str, guidelines: &str, tests: &str, files: &[CommunityFile]) -> String {
+    let mut out = format!("# Contributing to {title}\n\n");
+    out.push_str(if guidelines.is_empty() {
+        "Thank you for your interest in contributing!"
+    } else {
+        guidelines
+    });
+    out.push_str("\n\n");
+    if files.contains(&CommunityFile::CodeOfConduct) {
+        out.push_str(
+            "Everyone taking part is expected to follow the \
+             [Code of Conduct](CODE_OF_CONDUCT.md).\n\n",
+        );
+    }
+
+    out.push_str("## Reporting bugs and requesting features\n\n");
+    if repo.is_empty() {
+        out.push_str("Open an issue describing the problem or the idea.");
+    } else {
+        out.push_str(&format!(
+            "Open an issue at {} describing the problem or the idea.",
+            issues_url(repo, files)
+        ));
+    }
+    out.push_str(" For bugs, include the steps to reproduce, what you expected and what happened instead.");
+    if files.contains(&CommunityFile::GitHubTemplates) || files.contains(&CommunityFile::GitLabTemplates) {
+        out.push_str(" The bug report and feature request templates ask for all of this.");
+    }
+    out.push_str("\n\n");
+    if files.contains(&CommunityFile::Security) {
+        out.push_str(
+            "Please report security vulnerabilities privately as described in \
+             [SECURITY.md](SECURITY.md), not in a public issue.\n\n",
+        );
+    }
+
+    out.push_str("## Running the tests\n\n");
+    out.push_str(&steps(tests, "<Test instructions>"));
+
+    out.push_str("\n## Submitting changes\n\n");
+    out.push_str("1. Fork the repository and create a branch for your change.\n");
+    out.push_str("2. Keep the change focused and add tests where it makes sense.\n");
+    out.push_str("3. Make sure the tests pass.\n");
+    out.push_str("4. Open a pull request explaining what the change does and why.\n");
+    out
+}
+
+/// The YAML header GitHub reads an issue template's name and labels from.
+fn front_matter(name: &str, about: &str, label: &str) -> String {
+    format!("---\nname: {name}\nabout: {about}\ntitle: ''\nlabels: {label}\nassignees: ''\n---\n\n")
+}
+
+fn bug_report(title: &str) -> String {
+    format!(
+        "## Describe the bug\n\n\
+         A clear and concise description of what the bug is.\n\n\
+         ## To reproduce\n\n\
+         1. <First step>\n2. <Second step>\n3. <Third step>\n\n\
+         ## Expected behavior\n\n\
+         What you expected to happen instead.\n\n\
+         ## Environment\n\n\
+         - {title} version:\n\
+         - Operating system:\n\n\
+         ## Additional context\n\n\
+         Logs, screenshots or anything else that helps explain the problem.\n"
+    )
+}
+
+fn feature_request() -> String {
+    String::from(
+        "## Problem\n\n\
+         What are you trying to do, and what makes it hard today?\n\n\
+         ## Proposed solution\n\n\
+         What you would like to happen.\n\n\
+         ## Alternatives considered\n\n\
+         Other solutions or workarounds you have thought about.\n\n\
+         ## Additional context\n\n\
+         Examples, mockups or links to similar features elsewhere.\n",
+    )
+}
+
+fn pull_request(tests: &str, contributing_file: bool) -> String {
+    let mut out = String::from("## Description\n\n");
+    out.push_str("What does this change do, and why? Link the issue it fixes, e.g. `Fixes #123`.\n\n");
+    out.push_str("## Type of change\n\n");
+    out.push_str("- [ ] Bug fix\n- [ ] New feature\n- [ ] Breaking change\n- [ ] Documentation\n\n");
+    out.push_str("## Testing\n\n");
+    out.push_str("The tests pass when run with:\n\n");
+    out.push_str(&steps(tests, "<Test instructions>"));
+    out.push_str("\n## Checklist\n\n");
+    if contributing_file {
+        out.push_str("- [ ] I have read CONTRIBUTING.md\n");
+    }
+    out.push_str("- [ ] I have added tests that cover the change\n");
+    out.push_str("- [ ] I have updated the documentation\n");
+    out
+}
+
+fn security(title: &str, repo: &str, email: &str, files: &[CommunityFile]) -> String {
+    let mut out = String::from("# Security Policy\n\n");
+    out.push_str("## Supported Versions\n\n");
+    out.push_str(&format!(
+        "Security fixes are made for the latest release of {title}. Please upgrade before reporting an issue.\n\n"
+    ));
+    out.push_str("## Reporting a Vulnerability\n\n");
+    out.push_str("Please do not report security vulnerabilities through public issues.\n\n");
+    let mut channels = Vec::new();
+    if !email.is_empty() {
+        channels.push(format!("email [{email}](mailto:{email})"));
+    }
+    if !repo.is_empty() {
+        channels.push(if on_gitlab(files) {
+            format!("open a [confidential issue](https://gitlab.com/{repo}/-/issues/new?issue[confidential]=true)")
+        } else {
+            format!("use [private vulnerability reporting](https://github.com/{repo}/security/advisories/new)")
+        });
+    }
+    if !channels.is_empty() {
+        out.push_str(&format!("Instead, {}.\n\n", channels.join(" or ")));
+    }
+    out.push_str("Include as much of the following as you can:\n\n");
+    out.push_str("- the kind of issue and where it is in the code\n");
+    out.push_str("- the steps or configuration needed to reproduce it\n");
+    out.push_str("- its impact, and how an attacker might exploit it\n\n");
+    out.push_str(
+        "You will get a reply acknowledging the report, and updates as it is \
+         investigated and fixed. Please give us a chance to release a fix before \
+         disclosing the issue publicly.\n",
+    );
+    out
+}
+
+const CODE_OF_CONDUCT: &str = "\
+# Contributor Covenant Code of Conduct
+
+## Our Pledge
+
+We as members, contributors, and leaders pledge to make participation in our
+community a harassment-free experience for everyone, regardless of age, body
+size, visible or invisible disability, ethnicity, sex characteristics, gender
+identity and expression, level of experience, education, socio-economic status,
+nationality, personal appearance, race, caste, color, religion, or sexual
+identity and orientation.
+
+We pledge to act and interact in ways that contribute to an open, welcoming,
+diverse, inclusive, and healthy community.
+
+## Our Standards
+
+Examples of behavior that contributes to a positive environment for our
+community include:
+
+* Demonstrating empathy and kindness toward other people
+* Being respectful of differing opinions, viewpoints, and experiences
+* Giving and gracefully accepting constructive feedback
+* Accepting responsibility and apologizing to those affected by our mistakes,
+  and learning from the experience
+* Focusing on what is best not just for us as individuals, but for the overall
+  community
+
+Examples of unacceptable behavior include:
+
+* The use of sexualized language or imagery, and sexual attention or advances of
+  any kind
+* Trolling, insulting or derogatory comments, and personal or political attacks
+* Public or private harassment
+* Publishing others' private information, such as a physical or email address,
+  without their explicit permission
+* Other conduct which could reasonably be considered inappropriate in a
+  professional setting
+
+## Enforcement Responsibilities
+
+Community leaders are responsible for clarifying and enforcing our standards of
+acceptable behavior and will take appropriate and fair corrective action in
+response to any behavior that they deem inappropriate, threatening, offensive,
+or harmful.
+
+Community leaders have the right and responsibility to remove, edit, or reject
+comments, commits, code, wiki edits, issues, and other contributions that are
+not aligned to this Code of Conduct, and will communicate reasons for moderation
+decisions when appropriate.
+
+## Scope
+
+This Code of Conduct applies within all community spaces, and also applies when
+an individual is officially representing the community in public spaces.
+Examples of representing our community include using an official e-mail address,
+posting via an official social media account, or acting as an appointed
+representative at an online or offline event.
+
+## Enforcement
+
+Instances of abusive, harassing, or otherwise unacceptable behavior may be
+reported to the community leaders responsible for enforcement at
+{contact}.
+All complaints will be reviewed and investigated promptly and fairly.
+
+All community leaders are obligated to respect the privacy and security of the
+reporter of any incident.
+
+## Enforcement Guidelines
+
+Community leaders will follow these Community Impact Guidelines in determining
+the consequences for any action they deem in violation of this Code of Conduct:
+
+### 1. Correction
+
+**Community Impact**: Use of inappropriate language or other behavior deemed
+unprofessional or unwelcome in the community.
+
+**Consequence**: A private, written warning from community leaders, providing
+clarity around the nature of the violation and an explanation of why the
+behavior was inappropriate. A public apology may be requested.
+
+### 2. Warning
+
+**Community Impact**: A violation through a single incident or series of
+actions.
+
+**Consequence**: A warning with consequences for continued behavior. No
+interaction with the people involved, including unsolicited interaction with
+those enforcing the Code of Conduct, for a specified period of time. This
+includes avoiding interactions in community spaces as well as external channels
+like social media. Violating these terms may lead to a temporary or permanent
+ban.
+
+### 3. Temporary Ban
+
+**Community Impact**: A serious violation of community standards, including
+sustained inappropriate behavior.
+
+**Consequence**: A temporary ban from any sort of interaction or public
+communication with the community for a specified period of time. No public or
+private interaction with the people involved, including unsolicited interaction
+with those enforcing the Code of Conduct, is allowed during this period.
+Violating these terms may lead to a permanent ban.
+
+### 4. Permanent Ban
+
+**Community Impact**: Demonstrating a pattern of violation of community
+standards, including sustained inappropriate behavior, harassment of an
+individual, or aggression toward or disparagement of classes of individuals.
+
+**Consequence**: A permanent ban from any sort of public interaction within the
+community.
+
+## Attribution
+
+This Code of Conduct is adapted from the [Contributor Covenant][homepage],
+version 2.1, available at
+[https://www.contributor-covenant.org/version/2/1/code_of_conduct.html][v2.1].
+
+Community Impact Guidelines were inspired by
+[Mozilla's code of conduct enforcement ladder][Mozilla CoC].
+
+For answers to common questions about this code of conduct, see the FAQ at
+[https://www.contributor-covenant.org/faq][FAQ]. Translations are available at
+[https://www.contributor-covenant.org/translations][translations].
+
+[homepage]: https://www.contributor-covenant.org
+[v2.1]: https://www.contributor-covenant.org/version/2/1/code_of_conduct.html
+[Mozilla CoC]: https://github.com/mozilla/diversity
+[FAQ]: https://www.contributor-covenant.org/faq
+[translations]: https://www.contributor-covenant.org/translations
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::default_fields;
+    use crate::i18n::Lang;
+    use crate::lint;
+
+    fn render_all(files: &[CommunityFile]) -> Vec<(&'static str, String)> {
+        let mut fields = default_fields(Lang::En);
+        for field in &mut fields {
+            field.value = match field.id {
+                "repository" => "octocat/tool",
+                "contact_email" => "me@example.com",
+                _ => "",
+            }
+            .to_string();
+        }
+        files.iter().flat_map(|file| file.render(&fields, files)).collect()
+    }
+
+    #[test]
+    fn every_file_passes_lint() {
+        for (path, markdown) in render_all(&CommunityFile::ALL) {
+            // The linter does not know YAML front matter, so skip it
+            let markdown = match markdown.strip_prefix("---\n") {
+                Some(rest) => rest.split_once("---\n\n").map_or(rest, |(_, body)| body).to_string(),
+                None => markdown,
+            };
+            let issues: Vec<String> = lint::lint(&markdown).iter().map(|issue| issue.to_string()).collect();
+            assert!(issues.is_empty(), "{path}: {issues:#?}\n{markdown}");
+        }
+    }
+
+    #[test]
+    fn links_point_at_gitlab_with_only_its_templates() {
+        let files = [CommunityFile::Contributing, CommunityFile::Security, CommunityFile::GitLabTemplates];
+        let rendered = render_all(&files);
+        assert!(rendered[0].1.contains("https://gitlab.com/octocat/tool/-/issues "));
+        assert!(rendered[1].1.contains("https://gitlab.com/octocat/tool/-/issues/new?issue[confidential]=true"));
+        assert!(rendered.iter().all(|(_, markdown)| !markdown.contains("github.com")));
+        let rendered = render_all(&CommunityFile::ALL);
+        assert!(rendered[0].1.contains("https://github.com/octocat/tool/issues "));
+    }
+}
@@ -0,0 +1,290 @@
+//! `.readmegen.toml` configuration.
+//!
+//! Settings are layered, later layers winning:
+//!
+//! 1. built-in defaults
+//! 2. the user file, `$XDG_CONFIG_HOME/readmegen/config.toml`
+//!    (`~/.config/readmegen/config.toml` when the variable is unset)
+//! 3. the project file, `.readmegen.toml` in the current directory or the
+//!    closest parent that has one (or the file given with `--config`)
+//! 4. command line flags
+//!
+//! `[fields]`, `[icons]` and `[keys]` tables are merged key by key; every
+//! other setting is replaced as a whole.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::community::CommunityFile;
+use crate::i18n::Lang;
+use crate::preset::Preset;
+use crate::render::{Badge, Section};
+
+pub const PROJECT_FILE: &str = ".readmegen.toml";
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Project type preset id, e.g. `rust-lib`; the wizard starts past the
+    /// preset page.
+    pub preset: Option<String>,
+    /// Default values keyed by field id, e.g. `repository = "me/tool"`.
+    pub fields: BTreeMap<String, String>,
+    /// Section ids to render, in order.
+    pub sections: Option<Vec<String>>,
+    /// Path to a template file, relative to the config file.
+    pub template: Option<PathBuf>,
+    /// Badge ids to render, in order.
+    pub badges: Option<Vec<String>>,
+    /// Community file ids to write next to the README: `contributing`,
+    /// `code-of-conduct`, `security`, `github-templates`, `gitlab-templates`.
+    pub community: Option<Vec<String>>,
+    /// SPDX id or expression, e.g. `MIT OR Apache-2.0`, or a license name.
+    pub license: Option<String>,
+    /// Shorthand: `false` is the same as `heading_style = "plain"`.
+    pub emoji: Option<bool>,
+    /// `emoji`, `plain` or `custom` (icons from `[icons]`).
+    pub heading_style: Option<String>,
+    /// `atx` or `setext`.
+    pub heading_syntax: Option<String>,
+    /// Icons for `heading_style = "custom"`, keyed by section id or `toc`.
+    pub icons: BTreeMap<String, String>,
+    /// Where to write the README, relative to the config file; `-` is
+    /// stdout.
+    pub output: Option<String>,
+    /// Languages to generate; the first is written to `output`, the rest
+    /// to `README.<code>.md` beside it.
+    pub languages: Option<Vec<String>>,
+    /// Language of the interface itself; detected from `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG` when unset.
+    pub ui_language: Option<String>,
+    /// Key binding preset: `default`, `vim` or `emacs`.
+    pub keymap: Option<String>,
+    /// Bindings per action on top of the preset, e.g. `quit = ["q", ":q"]`.
+    pub keys: BTreeMap<String, Vec<String>>,
+    /// `dark`, `light`, `high-contrast` or `mono`; `mono` when unset and
+    /// `NO_COLOR` is set, `dark` otherwise.
+    pub theme: Option<String>,
+}
+
+impl Config {
+    /// Loads the user file and then the project file (or `explicit`).
+    pub fn load(explicit: Option<&Path>) -> Result<Config, String> {
+        let mut config = Config::default();
+        if let Some(path) = user_file() {
+            if path.is_file() {
+                config = config.merge(Config::from_file(&path)?);
+            }
+        }
+        let project = match explicit {
+            Some(path) => Some(path.to_path_buf()),
+            None => project_file(),
+        };
+        if let Some(path) = project {
+            config = config.merge(Config::from_file(&path)?);
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let mut config: Config =
+            toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+        if let Some(dir) = path.parent() {
+            if let Some(template) = &config.template {
+                config.template = Some(dir.join(template));
+            }
+            if let Some(output) = config.output.as_deref().filter(|output| output.trim() != "-") {
+                config.output = Some(dir.join(output).display().to_string());
+            }
+        }
+        config.validate().map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let Some(id) = &self.preset {
+            if Preset::from_id(id).is_none() {
+                return Err(format!("unknown preset '{id}'"));
+            }
+        }
+        for id in self.sections.iter().flatten() {
+            if Section::from_id(id).is_none() {
+                return Err(format!("unknown section '{id}'"));
+            }
+        }
+        if let Some(style) = &self.heading_style {
+            if !matches!(style.as_str(), "emoji" | "plain" | "custom") {
+                return Err(format!("unknown heading_style '{style}'"));
+            }
+        }
+        if let Some(syntax) = &self.heading_syntax {
+            if !matches!(syntax.as_str(), "atx" | "setext") {
+                return Err(format!("unknown heading_syntax '{syntax}'"));
+            }
+        }
+        if let Some(lang) = &self.ui_language {
+            if Lang::from_code(lang).is_none() {
+                return Err(format!("unsupported ui_language '{lang}'"));
+            }
+        }
+        for id in self.community.iter().flatten() {
+            if CommunityFile::from_id(id).is_none() {
+                return Err(format!("unknown community file '{id}'"));
+            }
+        }
+        for id in self.badges.iter().flatten() {
+            if Badge::from_id(id).is_none() {
+                return Err(format!("unknown badge '{id}'"));
+            }
+        }
+        Ok(())
+    }
+
+    /// `other` layered on top of `self`.
+    pub fn merge(mut self, other: Config) -> Config {
+        self.fields.extend(other.fields);
+        self.icons.extend(other.icons);
+        self.keys.extend(other.keys);
+        Config {
+            preset: other.preset.or(self.preset),
+            fields: self.fields,
+            icons: self.icons,
+            keys: self.keys,
+            keymap: other.keymap.or(self.keymap),
+            theme: other.theme.or(self.theme),
+            sections: other.sections.or(self.sections),
+            template: other.template.or(self.template),
+            badges: other.badges.or(self.badges),
+            community: other.community.or(self.community),
+            license: other.license.or(self.license),
+            emoji: other.emoji.or(self.emoji),
+            heading_style: other.heading_style.or(self.heading_style),
+            heading_syntax: other.heading_syntax.or(self.heading_syntax),
+            output: other.output.or(self.output),
+            languages: other.languages.or(self.languages),
+            ui_language: other.ui_language.or(self.ui_language),
+        }
+    }
+}
+
+fn user_file() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("readmegen").join("config.toml"))
+}
+
+fn project_file() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory under the system temp dir for one test.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("readmegen-config-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn paths_are_relative_to_the_config_file() {
+        let dir = temp_dir("paths");
+        let path = dir.join(PROJECT_FILE);
+        fs::write(&path, "output = \"docs/README.md\"\ntemplate = \"readme.tmpl\"\n").unwrap();
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(config.output, Some(dir.join("docs/README.md").display().to_string()));
+        assert_eq!(config.template, Some(dir.join("readme.tmpl")));
+
+        fs::write(&path, "output = \"-\"\n").unwrap();
+        assert_eq!(Config::from_file(&path).unwrap().output.as_deref(), Some("-"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn parse(text: &str) -> Config {
+        toml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn tables_merge_key_by_key() {
+        let user = parse(
+            "[fields]\nrepository = \"me/tool\"\nauthors = \"Me\"\n\
+             [icons]\nabout = \"A\"\ntoc = \"T\"\n\
+             [keys]\nquit = [\"q\"]\nsave = [\"ctrl-s\"]\n",
+        );
+        let project = parse(
+            "[fields]\nrepository = \"us/tool\"\ntitle = \"Tool\"\n\
+             [icons]\ntoc = \"X\"\n\
+             [keys]\nquit = [\":q\"]\n",
+        );
+        let config = user.merge(project);
+        let fields: Vec<(&str, &str)> = config.fields.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(fields, [("authors", "Me"), ("repository", "us/tool"), ("title", "Tool")]);
+        assert_eq!(config.icons["about"], "A");
+        assert_eq!(config.icons["toc"], "X");
+        assert_eq!(config.keys["quit"], [":q"]);
+        assert_eq!(config.keys["save"], ["ctrl-s"]);
+    }
+
+    #[test]
+    fn other_settings_are_replaced_whole() {
+        let user = parse(
+            "sections = [\"about\", \"usage\"]\nbadges = [\"license\"]\n\
+             theme = \"light\"\nlanguages = [\"en\", \"de\"]\noutput = \"README.md\"\n",
+        );
+        let project = parse("sections = [\"usage\"]\nlanguages = [\"fr\"]\n");
+        let config = user.merge(project);
+        assert_eq!(config.sections, Some(vec![String::from("usage")]));
+        assert_eq!(config.languages, Some(vec![String::from("fr")]));
+        // Settings the later layer leaves out keep the earlier value
+        assert_eq!(config.badges, Some(vec![String::from("license")]));
+        assert_eq!(config.theme.as_deref(), Some("light"));
+        assert_eq!(config.output.as_deref(), Some("README.md"));
+
+        // An empty list still replaces
+        let config = config.merge(parse("sections = []\n"));
+        assert_eq!(config.sections, Some(Vec::new()));
+    }
+
+    #[test]
+    fn validate_rejects_unknown_ids() {
+        assert!(parse("").validate().is_ok());
+        assert!(parse("heading_style = \"custom\"\nheading_syntax = \"setext\"\nui_language = \"de\"\n")
+            .validate()
+            .is_ok());
+        let error = |text: &str| parse(text).validate().unwrap_err();
+        assert_eq!(error("preset = \"cobol\"\n"), "unknown preset 'cobol'");
+        assert_eq!(error("sections = [\"about\", \"bogus\"]\n"), "unknown section 'bogus'");
+        assert_eq!(error("heading_style = \"fancy\"\n"), "unknown heading_style 'fancy'");
+        assert_eq!(error("heading_syntax = \"html\"\n"), "unknown heading_syntax 'html'");
+        assert_eq!(error("ui_language = \"xx\"\n"), "unsupported ui_language 'xx'");
+        assert_eq!(error("community = [\"wiki\"]\n"), "unknown community file 'wiki'");
+        assert_eq!(error("badges = [\"downloads\"]\n"), "unknown badge 'downloads'");
+    }
+
+    #[test]
+    fn from_file_names_the_file_in_errors() {
+        let dir = temp_dir("errors");
+        let path = dir.join(PROJECT_FILE);
+        fs::write(&path, "theme = \"dark\"\ncolour = \"red\"\n").unwrap();
+        let error = Config::from_file(&path).err().unwrap();
+        assert!(error.starts_with(&path.display().to_string()), "{error}");
+        assert!(error.contains("colour"), "{error}");
+
+        fs::write(&path, "badges = [\"downloads\"]\n").unwrap();
+        assert_eq!(Config::from_file(&path).err().unwrap(), format!("{}: unknown badge 'downloads'", path.display()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+//! Line based diffs, for showing what writing a README would change.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Change<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// The line by line edit script turning `old` into `new`, from their
+/// longest common subsequence.
+pub fn changes<'a>(old: &'a str, new: &'a str) -> Vec<Change<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Only the part between the common prefix and suffix needs the table.
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    // lcs[i][j]: length of the longest common subsequence of a[i..], b[j..]
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes: Vec<Change> = old[..prefix].iter().map(|line| Change::Same(line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            changes.push(Change::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            // On a tie removals go first, so `-old` comes before `+new`
+            changes.push(Change::Removed(a[i]));
+            i += 1;
+        } else {
+            changes.push(Change::Added(b[j]));
+            j += 1;
+        }
+    }
+    changes.extend(old[old.len() - suffix..].iter().map(|line| Change::Same(line)));
+    changes
+}
+
+/// `old` and `new` as a unified diff with `context` unchanged lines around
+/// each change. Empty when the two are the same line for line.
+pub fn unified(old: &str, new: &str, old_name: &str, new_name: &str, context: usize) -> String {
+    let changes = changes(old, new);
+
+    // Ranges of `changes` to show, overlapping ones merged.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, change) in changes.iter().enumerate() {
+        if matches!(change, Change::Same(_)) {
+            continue;
+        }
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(changes.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- {old_name}\n+++ {new_name}\n");
+    for (start, end) in hunks {
+        let before = &changes[..start];
+        let hunk = &changes[start..end];
+        let old_before = before.iter().filter(|c| !matches!(c, Change::Added(_))).count();
+        let new_before = before.iter().filter(|c| !matches!(c, Change::Removed(_))).count();
+        let old_len = hunk.iter().filter(|c| !matches!(c, Change::Added(_))).count();
+        let new_len = hunk.iter().filter(|c| !matches!(c, Change::Removed(_))).count();
+        // An empty range is numbered after the line it follows.
+        let old_start = old_before + usize::from(old_len > 0);
+        let new_start = new_before + usize::from(new_len > 0);
+        out.push_str(&format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@\n"));
+        for change in hunk {
+            let line = match change {
+                Change::Same(line) => format!(" {line}"),
+                Change::Removed(line) => format!("-{line}"),
+                Change::Added(line) => format!("+{line}"),
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(old: &str, new: &str, context: usize) -> String {
+        unified(old, new, "a/README.md", "b/README.md", context)
+    }
+
+    #[test]
+    fn same_text_has_no_diff() {
+        assert_eq!(diff("a\nb\n", "a\nb\n", 3), "");
+    }
+
+    #[test]
+    fn removals_come_before_additions() {
+        let changes = changes("a\nold\nc", "a\nnew\nc");
+        assert!(changes == [Change::Same("a"), Change::Removed("old"), Change::Added("new"), Change::Same("c")]);
+        assert_eq!(
+            diff("one\ntwo\n", "uno\ndos\n", 0),
+            "--- a/README.md\n+++ b/README.md\n@@ -1,2 +1,2 @@\n-one\n-two\n+uno\n+dos\n"
+        );
+    }
+
+    #[test]
+    fn hunk_with_context() {
+        assert_eq!(
+            diff("1\n2\n3\n4\n5\n", "1\n2\nX\n4\n5\n", 1),
+            "--- a/README.md\n+++ b/README.md\n@@ -2,3 +2,3 @@\n 2\n-3\n+X\n 4\n"
+        );
+    }
+
+    #[test]
+    fn distant_changes_get_separate_hunks() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "1\nB\n3\n4\n5\n6\n7\n8\nI\n10\n";
+        let headers: Vec<String> = diff(old, new, 1)
+            .lines()
+            .filter(|line| line.starts_with("@@"))
+            .map(str::to_string)
+            .collect();
+        assert_eq!(headers, ["@@ -1,3 +1,3 @@", "@@ -8,3 +8,3 @@"]);
+        // With more context the two overlap and merge
+        let headers = diff(old, new, 3).lines().filter(|line| line.starts_with("@@")).count();
+        assert_eq!(headers, 1);
+    }
+
+    #[test]
+    fn empty_ranges_are_numbered_after_the_line_before() {
+        assert_eq!(diff("", "new\n", 3), "--- a/README.md\n+++ b/README.md\n@@ -0,0 +1,1 @@\n+new\n");
+        assert_eq!(diff("old\n", "", 3), "--- a/README.md\n+++ b/README.md\n@@ -1,1 +0,0 @@\n-old\n");
+        assert!(diff("a\nb\n", "a\nx\nb\n", 0).contains("@@ -1,0 +2,1 @@\n+x\n"));
+        assert!(diff("a\nx\nb\n", "a\nb\n", 0).contains("@@ -2,1 +1,0 @@\n-x\n"));
+    }
+}
@@ -0,0 +1,165 @@
+//! Building and writing a README without the TUI.
+
+use std::io;
+use std::path::Path;
+
+use crate::community::CommunityFile;
+use crate::field::{default_fields, Field};
+use crate::i18n::Lang;
+use crate::output::{self, WriteReport};
+use crate::preset::Preset;
+use crate::render::{self, Badge, HeadingStyle, HeadingSyntax, Options, Section};
+
+pub const DEFAULT_LICENSE: &str = "MIT License";
+
+/// A README ready to be rendered or written.
+pub struct Readme {
+    fields: Vec<Field>,
+    license: String,
+    options: Options,
+}
+
+impl Readme {
+    pub fn builder() -> ReadmeBuilder {
+        ReadmeBuilder {
+            readme: Readme {
+                fields: default_fields(Lang::En),
+                license: String::from(DEFAULT_LICENSE),
+                options: Options::default(),
+            },
+            unknown: Vec::new(),
+        }
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    pub fn render(&self) -> String {
+        render::render(&self.fields, &self.license, &self.options)
+    }
+
+    /// Writes the rendered README to `path`, creating parent directories and
+    /// backing up an existing file as `<name>.bak`. Community files are not
+    /// written; see [`community_files`](Self::community_files).
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> io::Result<WriteReport> {
+        output::write_readme(path.as_ref(), &self.render())
+    }
+
+    /// Paths and contents of the community files, see
+    /// [`ReadmeBuilder::community`]. Paths are relative to the README's
+    /// directory, which is where the README links them.
+    pub fn community_files(&self) -> Vec<(&'static str, String)> {
+        let files = &self.options.community;
+        files
+            .iter()
+            .flat_map(|file| file.render(&self.fields, files))
+            .collect()
+    }
+}
+
+/// Collects field values and render options for a [`Readme`].
+pub struct ReadmeBuilder {
+    readme: Readme,
+    unknown: Vec<String>,
+}
+
+impl ReadmeBuilder {
+    /// Sets the field with the given id (see [`default_fields`]).
+    pub fn field<S: Into<String>>(mut self, id: &str, value: S) -> Self {
+        match self.readme.fields.iter_mut().find(|field| field.id == id) {
+            Some(field) => field.value = value.into(),
+            None => self.unknown.push(id.to_string()),
+        }
+        self
+    }
+
+    /// Sets a list field from separate items.
+    pub fn list<I, S>(self, id: &str, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let value = items
+            .into_iter()
+            .map(|item| item.as_ref().to_string())
+            .collect::<Vec<_>>()
+            .join(";");
+        self.field(id, value)
+    }
+
+    pub fn repository<S: Into<String>>(self, slug: S) -> Self {
+        self.field("repository", slug)
+    }
+
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        self.field("title", title)
+    }
+
+    pub fn license<S: Into<String>>(mut self, license: S) -> Self {
+        self.readme.license = license.into();
+        self
+    }
+
+    /// Sections, badges and Usage code block language of a project type
+    /// preset. Later calls to [`sections`](Self::sections) and
+    /// [`badges`](Self::badges) override them.
+    pub fn preset(mut self, preset: &Preset) -> Self {
+        self.readme.options.sections = preset.sections.to_vec();
+        self.readme.options.badges = preset.badges.to_vec();
+        self.readme.options.usage_lang = preset.usage_lang.to_string();
+        self
+    }
+
+    pub fn sections<I: IntoIterator<Item = Section>>(mut self, sections: I) -> Self {
+        self.readme.options.sections = sections.into_iter().collect();
+        self
+    }
+
+    pub fn badges<I: IntoIterator<Item = Badge>>(mut self, badges: I) -> Self {
+        self.readme.options.badges = badges.into_iter().collect();
+        self
+    }
+
+    pub fn heading_style(mut self, style: HeadingStyle) -> Self {
+        self.readme.options.heading_style = style;
+        self
+    }
+
+    pub fn heading_syntax(mut self, syntax: HeadingSyntax) -> Self {
+        self.readme.options.heading_syntax = syntax;
+        self
+    }
+
+    /// Template text with `{{placeholders}}`, see [`render`].
+    pub fn template<S: Into<String>>(mut self, template: S) -> Self {
+        self.readme.options.template = Some(template.into());
+        self
+    }
+
+    /// Community files to write next to the README and link from its
+    /// Contributing section.
+    pub fn community<I: IntoIterator<Item = CommunityFile>>(mut self, files: I) -> Self {
+        self.readme.options.community = files.into_iter().collect();
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.readme.options.lang = lang;
+        self
+    }
+
+    /// Fails on unknown field ids and on values that do not pass their
+    /// field's validator.
+    pub fn build(self) -> Result<Readme, String> {
+        if let Some(id) = self.unknown.first() {
+            return Err(format!("unknown field '{id}'"));
+        }
+        for field in &self.readme.fields {
+            if let Some(err) = field.error() {
+                return Err(format!("{}: {err}", field.id));
+            }
+        }
+        Ok(self.readme)
+    }
+}
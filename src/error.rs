@@ -0,0 +1,40 @@
+use std::fmt;
+use std::io;
+
+/// Everything that can stop the program, reported on stderr once the
+/// terminal is back to normal.
+pub enum Error {
+    /// The command line could not be parsed; the usage text follows.
+    Usage(String),
+    /// A config file, preset, language or other setting was rejected.
+    Config(String),
+    /// The terminal could not be set up, drawn to or read from.
+    Terminal(io::Error),
+    /// Reading or writing a file failed.
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Usage(_) | Error::Config(_) => 2,
+            Error::Terminal(_) | Error::Io(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Usage(msg) | Error::Config(msg) => f.write_str(msg),
+            Error::Terminal(err) => write!(f, "terminal: {err}"),
+            Error::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
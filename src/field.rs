@@ -0,0 +1,81 @@
+//! The README field model.
+
+use crate::community::CommunityFile;
+use crate::i18n::Lang;
+use crate::messages;
+use crate::render::Section;
+use crate::validate::Validator;
+
+/// One input of the README, e.g. the project title or the feature list.
+/// List fields hold their items separated by semicolons.
+pub struct Field {
+    /// Stable identifier used by config files, templates and the renderer.
+    pub id: &'static str,
+    pub name: String,
+    pub value: String,
+    pub description: String,
+    pub validator: Option<Validator>,
+}
+
+impl Field {
+    /// An empty field with its name and description in `lang`.
+    pub fn new(id: &'static str, validator: Option<Validator>, lang: Lang) -> Field {
+        Field {
+            id,
+            name: messages::text(lang, &format!("field.{id}.name")).to_string(),
+            value: String::new(),
+            description: messages::text(lang, &format!("field.{id}.description")).to_string(),
+            validator,
+        }
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.validator.and_then(|v| v.check(&self.value).err())
+    }
+}
+
+/// Fields every README is built from, whatever its sections.
+pub const HEADER_FIELDS: [&str; 3] = ["repository", "title", "short_description"];
+
+/// Every README field id with its validator, in the order they are asked for.
+const FIELDS: [(&str, Option<Validator>); 16] = [
+    ("repository", Some(Validator::RepoSlug)),
+    ("title", None),
+    ("short_description", None),
+    ("description", None),
+    ("screenshots", Some(Validator::List)),
+    ("features", Some(Validator::List)),
+    ("technologies", Some(Validator::List)),
+    ("prerequisites", Some(Validator::List)),
+    ("installation", Some(Validator::List)),
+    ("usage", None),
+    ("api", None),
+    ("deployment", Some(Validator::List)),
+    ("contributing", None),
+    ("tests", Some(Validator::List)),
+    ("contact_email", Some(Validator::Email)),
+    ("authors", Some(Validator::Authors)),
+];
+
+/// The standard set of README fields, in the order they are asked for.
+pub fn default_fields(lang: Lang) -> Vec<Field> {
+    FIELDS
+        .iter()
+        .map(|&(id, validator)| Field::new(id, validator, lang))
+        .collect()
+}
+
+/// Splits `fields` into the ones needed by `sections` and `community`
+/// files (plus the header fields) and the rest, both in the standard order.
+pub fn partition(
+    mut fields: Vec<Field>,
+    sections: &[Section],
+    community: &[CommunityFile],
+) -> (Vec<Field>, Vec<Field>) {
+    fields.sort_by_key(|field| FIELDS.iter().position(|(id, _)| *id == field.id));
+    fields.into_iter().partition(|field| {
+        HEADER_FIELDS.contains(&field.id)
+            || sections.iter().any(|section| section.fields().contains(&field.id))
+            || community.iter().any(|file| file.fields().contains(&field.id))
+    })
+}
@@ -0,0 +1,24 @@
+//! Fuzzy matching for the command palette.
+
+/// How well `query` matches `text`, higher being better, or `None` when
+/// the characters of `query` do not all appear in `text` in order. Case and
+/// spaces in the query are ignored; runs of adjacent characters and matches
+/// at the start of a word score extra, so `lic` ranks "License" above
+/// "Public API".
+pub fn score(query: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut next = 0;
+    for wanted in query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase) {
+        let found = next + text[next..].iter().position(|&c| c == wanted)?;
+        score += 1;
+        if found > 0 && found == next {
+            score += 4;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        next = found + 1;
+    }
+    Some(score)
+}
@@ -0,0 +1,53 @@
+//! Undo and redo for committed field edits.
+
+use serde::{Deserialize, Serialize};
+
+/// Oldest edits are dropped past this many.
+const LIMIT: usize = 200;
+
+/// One committed change of a field value.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Edit {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct History {
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+impl History {
+    /// Records an edit; a new edit makes the undone ones unreachable.
+    pub fn record(&mut self, edit: Edit) {
+        if edit.before == edit.after {
+            return;
+        }
+        self.redo.clear();
+        self.undo.push(edit);
+        if self.undo.len() > LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    /// The edit to revert, which moves to the redo stack.
+    pub fn undo(&mut self) -> Option<Edit> {
+        let edit = self.undo.pop()?;
+        self.redo.push(edit.clone());
+        Some(edit)
+    }
+
+    /// The edit to apply again, which moves back to the undo stack.
+    pub fn redo(&mut self) -> Option<Edit> {
+        let edit = self.redo.pop()?;
+        self.undo.push(edit.clone());
+        Some(edit)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty() && self.redo.is_empty()
+    }
+}
@@ -0,0 +1,322 @@
+//! Translations for text the generator writes into the README.
+//!
+//! Every string is looked up by key; a key missing from a translation falls
+//! back to English so partial translations still produce a complete file.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang {
+    En,
+    Es,
+    De,
+    Fr,
+    Ja,
+    ZhCn,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 6] = [Lang::En, Lang::Es, Lang::De, Lang::Fr, Lang::Ja, Lang::ZhCn];
+
+    /// BCP 47 tag, also used in variant file names (`README.zh-CN.md`).
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Es => "es",
+            Lang::De => "de",
+            Lang::Fr => "fr",
+            Lang::Ja => "ja",
+            Lang::ZhCn => "zh-CN",
+        }
+    }
+
+    /// Name of the language in the language itself, for the switcher links.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Lang::En => "English",
+            Lang::Es => "Español",
+            Lang::De => "Deutsch",
+            Lang::Fr => "Français",
+            Lang::Ja => "日本語",
+            Lang::ZhCn => "简体中文",
+        }
+    }
+
+    /// Accepts tags like `de`, `de-AT`, `zh_CN` or `zh-Hans`.
+    pub fn from_code(code: &str) -> Option<Lang> {
+        let code = code.trim().replace('_', "-").to_lowercase();
+        let primary = code.split('-').next().unwrap_or_default();
+        match primary {
+            "en" => Some(Lang::En),
+            "es" => Some(Lang::Es),
+            "de" => Some(Lang::De),
+            "fr" => Some(Lang::Fr),
+            "ja" => Some(Lang::Ja),
+            "zh" => Some(Lang::ZhCn),
+            _ => None,
+        }
+    }
+}
+
+/// README text for `key` in `lang`.
+pub fn readme(lang: Lang, key: &str) -> &'static str {
+    let translated = match lang {
+        Lang::En => None,
+        Lang::Es => readme_es(key),
+        Lang::De => readme_de(key),
+        Lang::Fr => readme_fr(key),
+        Lang::Ja => readme_ja(key),
+        Lang::ZhCn => readme_zh_cn(key),
+    };
+    translated.or_else(|| readme_en(key)).unwrap_or("")
+}
+
+fn readme_en(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "Table of Contents",
+        "about" => "About",
+        "features" => "Features",
+        "built-with" => "Built With",
+        "getting-started" => "Getting Started",
+        "prerequisites" => "Prerequisites",
+        "installation" => "Installation",
+        "usage" => "Usage",
+        "api-documentation" => "API Documentation",
+        "testing" => "Testing",
+        "contributing" => "Contributing",
+        "changelog" => "Changelog",
+        "license" => "License",
+        "authors" => "Authors",
+        "documentation" => "Documentation",
+        "report-bug" => "Report Bug",
+        "request-feature" => "Request Feature",
+        "license-sentence" => "This project is licensed under the {license} - see the [LICENSE](LICENSE) file for details.",
+        "license-sentence.any" => "This project is licensed under any of the following licenses, at your option:",
+        "license-sentence.all" => "This project is licensed under all of the following licenses:",
+        "changelog-sentence" => "Notable changes in each release are listed in [CHANGELOG.md](CHANGELOG.md).",
+        "footer" => "Made with ❤️ by contributors",
+        "placeholder.features" => "<Features of your project>",
+        "placeholder.technologies" => "<Technologies used>",
+        "placeholder.prerequisites" => "<Prerequisites>",
+        "placeholder.installation" => "<Installation steps>",
+        "placeholder.tests" => "<Test instructions>",
+        "placeholder.authors" => "<Project authors>",
+        "placeholder.tech-badges" => "<Technology badges>",
+        "screenshots" => "Screenshots",
+        "deployment" => "Deployment",
+        "placeholder.screenshots" => "<Screenshots>",
+        "placeholder.deployment" => "<Deployment steps>",
+        "community.contributing" => "See [{file}]({file}) for how to report bugs, run the tests and submit changes.",
+        "community.code-of-conduct" => "This project follows a [Code of Conduct]({file}).",
+        "community.security" => "To report a security vulnerability, see [{file}]({file}).",
+        _ => return None,
+    })
+}
+
+fn readme_es(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "Tabla de contenidos",
+        "about" => "Acerca de",
+        "features" => "Características",
+        "built-with" => "Construido con",
+        "getting-started" => "Primeros pasos",
+        "prerequisites" => "Requisitos previos",
+        "installation" => "Instalación",
+        "usage" => "Uso",
+        "api-documentation" => "Documentación de la API",
+        "testing" => "Pruebas",
+        "contributing" => "Contribuir",
+        "changelog" => "Registro de cambios",
+        "license" => "Licencia",
+        "authors" => "Autores",
+        "documentation" => "Documentación",
+        "report-bug" => "Reportar un error",
+        "request-feature" => "Solicitar una función",
+        "license-sentence" => "Este proyecto está licenciado bajo la {license}; consulta el archivo [LICENSE](LICENSE) para más detalles.",
+        "license-sentence.any" => "Este proyecto está licenciado bajo cualquiera de las siguientes licencias, a tu elección:",
+        "license-sentence.all" => "Este proyecto está licenciado bajo todas las licencias siguientes:",
+        "changelog-sentence" => "Los cambios relevantes de cada versión se recogen en [CHANGELOG.md](CHANGELOG.md).",
+        "footer" => "Hecho con ❤️ por los colaboradores",
+        "placeholder.features" => "<Características de tu proyecto>",
+        "placeholder.technologies" => "<Tecnologías utilizadas>",
+        "placeholder.prerequisites" => "<Requisitos previos>",
+        "placeholder.installation" => "<Pasos de instalación>",
+        "placeholder.tests" => "<Instrucciones para las pruebas>",
+        "placeholder.authors" => "<Autores del proyecto>",
+        "placeholder.tech-badges" => "<Insignias de tecnologías>",
+        "screenshots" => "Capturas de pantalla",
+        "deployment" => "Despliegue",
+        "placeholder.screenshots" => "<Capturas de pantalla>",
+        "placeholder.deployment" => "<Pasos de despliegue>",
+        "community.contributing" => "Consulta [{file}]({file}) para saber cómo reportar errores, ejecutar las pruebas y enviar cambios.",
+        "community.code-of-conduct" => "Este proyecto sigue un [Código de conducta]({file}).",
+        "community.security" => "Para reportar una vulnerabilidad de seguridad, consulta [{file}]({file}).",
+        _ => return None,
+    })
+}
+
+fn readme_de(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "Inhaltsverzeichnis",
+        "about" => "Über das Projekt",
+        "features" => "Funktionen",
+        "built-with" => "Erstellt mit",
+        "getting-started" => "Erste Schritte",
+        "prerequisites" => "Voraussetzungen",
+        "installation" => "Installation",
+        "usage" => "Verwendung",
+        "api-documentation" => "API-Dokumentation",
+        "testing" => "Tests",
+        "contributing" => "Mitwirken",
+        "changelog" => "Änderungsprotokoll",
+        "license" => "Lizenz",
+        "authors" => "Autoren",
+        "documentation" => "Dokumentation",
+        "report-bug" => "Fehler melden",
+        "request-feature" => "Funktion vorschlagen",
+        "license-sentence" => "Dieses Projekt steht unter der {license} – Details stehen in der Datei [LICENSE](LICENSE).",
+        "license-sentence.any" => "Dieses Projekt steht nach deiner Wahl unter einer der folgenden Lizenzen:",
+        "license-sentence.all" => "Dieses Projekt steht unter allen folgenden Lizenzen:",
+        "changelog-sentence" => "Die wichtigsten Änderungen jeder Version stehen in [CHANGELOG.md](CHANGELOG.md).",
+        "footer" => "Mit ❤️ von den Mitwirkenden erstellt",
+        "placeholder.features" => "<Funktionen deines Projekts>",
+        "placeholder.technologies" => "<Verwendete Technologien>",
+        "placeholder.prerequisites" => "<Voraussetzungen>",
+        "placeholder.installation" => "<Installationsschritte>",
+        "placeholder.tests" => "<Anleitung zum Testen>",
+        "placeholder.authors" => "<Projektautoren>",
+        "placeholder.tech-badges" => "<Technologie-Badges>",
+        "screenshots" => "Bildschirmfotos",
+        "deployment" => "Bereitstellung",
+        "placeholder.screenshots" => "<Bildschirmfotos>",
+        "placeholder.deployment" => "<Schritte zur Bereitstellung>",
+        "community.contributing" => "In [{file}]({file}) steht, wie du Fehler meldest, die Tests ausführst und Änderungen einreichst.",
+        "community.code-of-conduct" => "Für dieses Projekt gilt ein [Verhaltenskodex]({file}).",
+        "community.security" => "Sicherheitslücken meldest du wie in [{file}]({file}) beschrieben.",
+        _ => return None,
+    })
+}
+
+fn readme_fr(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "Table des matières",
+        "about" => "À propos",
+        "features" => "Fonctionnalités",
+        "built-with" => "Construit avec",
+        "getting-started" => "Pour commencer",
+        "prerequisites" => "Prérequis",
+        "installation" => "Installation",
+        "usage" => "Utilisation",
+        "api-documentation" => "Documentation de l'API",
+        "testing" => "Tests",
+        "contributing" => "Contribuer",
+        "changelog" => "Journal des modifications",
+        "license" => "Licence",
+        "authors" => "Auteurs",
+        "documentation" => "Documentation",
+        "report-bug" => "Signaler un bug",
+        "request-feature" => "Proposer une fonctionnalité",
+        "license-sentence" => "Ce projet est distribué sous {license} - voir le fichier [LICENSE](LICENSE) pour plus de détails.",
+        "license-sentence.any" => "Ce projet est distribué, à votre choix, sous l'une des licences suivantes :",
+        "license-sentence.all" => "Ce projet est distribué sous l'ensemble des licences suivantes :",
+        "changelog-sentence" => "Les changements notables de chaque version sont listés dans [CHANGELOG.md](CHANGELOG.md).",
+        "footer" => "Fait avec ❤️ par les contributeurs",
+        "placeholder.features" => "<Fonctionnalités de votre projet>",
+        "placeholder.technologies" => "<Technologies utilisées>",
+        "placeholder.prerequisites" => "<Prérequis>",
+        "placeholder.installation" => "<Étapes d'installation>",
+        "placeholder.tests" => "<Instructions de test>",
+        "placeholder.authors" => "<Auteurs du projet>",
+        "placeholder.tech-badges" => "<Badges des technologies>",
+        "screenshots" => "Captures d'écran",
+        "deployment" => "Déploiement",
+        "placeholder.screenshots" => "<Captures d'écran>",
+        "placeholder.deployment" => "<Étapes de déploiement>",
+        "community.contributing" => "Consultez [{file}]({file}) pour savoir comment signaler un bug, lancer les tests et proposer des modifications.",
+        "community.code-of-conduct" => "Ce projet suit un [code de conduite]({file}).",
+        "community.security" => "Pour signaler une vulnérabilité, consultez [{file}]({file}).",
+        _ => return None,
+    })
+}
+
+fn readme_ja(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "目次",
+        "about" => "概要",
+        "features" => "特徴",
+        "built-with" => "使用技術",
+        "getting-started" => "はじめに",
+        "prerequisites" => "前提条件",
+        "installation" => "インストール",
+        "usage" => "使い方",
+        "api-documentation" => "API ドキュメント",
+        "testing" => "テスト",
+        "contributing" => "コントリビュート",
+        "changelog" => "変更履歴",
+        "license" => "ライセンス",
+        "authors" => "作者",
+        "documentation" => "ドキュメント",
+        "report-bug" => "バグを報告",
+        "request-feature" => "機能をリクエスト",
+        "license-sentence" => "このプロジェクトは {license} の下で公開されています。詳細は [LICENSE](LICENSE) ファイルをご覧ください。",
+        "license-sentence.any" => "このプロジェクトは、以下のいずれかのライセンスを選択して利用できます。",
+        "license-sentence.all" => "このプロジェクトは、以下のすべてのライセンスの下で公開されています。",
+        "changelog-sentence" => "各リリースの主な変更点は [CHANGELOG.md](CHANGELOG.md) にまとめています。",
+        "footer" => "コントリビューターが ❤️ を込めて作りました",
+        "placeholder.features" => "<プロジェクトの特徴>",
+        "placeholder.technologies" => "<使用している技術>",
+        "placeholder.prerequisites" => "<前提条件>",
+        "placeholder.installation" => "<インストール手順>",
+        "placeholder.tests" => "<テストの実行方法>",
+        "placeholder.authors" => "<プロジェクトの作者>",
+        "placeholder.tech-badges" => "<技術バッジ>",
+        "screenshots" => "スクリーンショット",
+        "deployment" => "デプロイ",
+        "placeholder.screenshots" => "<スクリーンショット>",
+        "placeholder.deployment" => "<デプロイ手順>",
+        "community.contributing" => "バグの報告、テストの実行、変更の提出方法は [{file}]({file}) をご覧ください。",
+        "community.code-of-conduct" => "このプロジェクトは[行動規範]({file})に従います。",
+        "community.security" => "セキュリティ上の脆弱性の報告については [{file}]({file}) をご覧ください。",
+        _ => return None,
+    })
+}
+
+fn readme_zh_cn(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "toc" => "目录",
+        "about" => "关于",
+        "features" => "功能特性",
+        "built-with" => "技术栈",
+        "getting-started" => "快速开始",
+        "prerequisites" => "环境要求",
+        "installation" => "安装",
+        "usage" => "使用方法",
+        "api-documentation" => "API 文档",
+        "testing" => "测试",
+        "contributing" => "参与贡献",
+        "changelog" => "更新日志",
+        "license" => "许可证",
+        "authors" => "作者",
+        "documentation" => "文档",
+        "report-bug" => "报告问题",
+        "request-feature" => "功能建议",
+        "license-sentence" => "本项目基于 {license} 授权 - 详情请参阅 [LICENSE](LICENSE) 文件。",
+        "license-sentence.any" => "本项目可任选以下许可证之一使用：",
+        "license-sentence.all" => "本项目同时受以下所有许可证约束：",
+        "changelog-sentence" => "各版本的重要变更记录在 [CHANGELOG.md](CHANGELOG.md) 中。",
+        "footer" => "由贡献者们用 ❤️ 制作",
+        "placeholder.features" => "<项目的功能特性>",
+        "placeholder.technologies" => "<使用的技术>",
+        "placeholder.prerequisites" => "<环境要求>",
+        "placeholder.installation" => "<安装步骤>",
+        "placeholder.tests" => "<测试说明>",
+        "placeholder.authors" => "<项目作者>",
+        "placeholder.tech-badges" => "<技术徽章>",
+        "screenshots" => "截图",
+        "deployment" => "部署",
+        "placeholder.screenshots" => "<截图>",
+        "placeholder.deployment" => "<部署步骤>",
+        "community.contributing" => "如何报告问题、运行测试和提交修改，请参阅 [{file}]({file})。",
+        "community.code-of-conduct" => "本项目遵循[行为准则]({file})。",
+        "community.security" => "如需报告安全漏洞，请参阅 [{file}]({file})。",
+        _ => return None,
+    })
+}
@@ -0,0 +1,573 @@
+//! Key bindings for the TUI.
+//!
+//! Bindings use vim's key notation: plain characters stand for themselves
+//! and other keys go in angle brackets, optionally with `C-` (Ctrl), `M-`
+//! (Alt) or `S-` (Shift), e.g. `<Enter>`, `<S-Tab>` or `<C-z>`. A binding
+//! can be a sequence such as `:w` or `<C-x><C-s>`.
+//!
+//! While text is being typed, in a field or the command palette, plain
+//! characters insert themselves, so only bindings that start with a named
+//! or modified key apply there.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl Key {
+    /// The key of a terminal event. Shift is dropped because it is already
+    /// part of the character (`J`) or the key code (`BackTab`).
+    pub fn from_event(event: KeyEvent) -> Key {
+        Key {
+            code: event.code,
+            modifiers: event.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    fn is_text(self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && self.modifiers.is_empty()
+    }
+
+    /// Label for the help bar, e.g. `Ctrl+Z` or `↑`.
+    pub fn label(self) -> String {
+        let name = match self.code {
+            KeyCode::Char(' ') => String::from("Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => c.to_string(),
+            KeyCode::Char(c) => c.to_uppercase().to_string(),
+            KeyCode::Enter => String::from("Enter"),
+            KeyCode::Tab => String::from("Tab"),
+            KeyCode::BackTab => String::from("Shift+Tab"),
+            KeyCode::Esc => String::from("Esc"),
+            KeyCode::Backspace => String::from("Backspace"),
+            KeyCode::Delete => String::from("Del"),
+            KeyCode::Up => String::from("↑"),
+            KeyCode::Down => String::from("↓"),
+            KeyCode::Left => String::from("←"),
+            KeyCode::Right => String::from("→"),
+            KeyCode::PageUp => String::from("PgUp"),
+            KeyCode::PageDown => String::from("PgDn"),
+            KeyCode::Home => String::from("Home"),
+            KeyCode::End => String::from("End"),
+            KeyCode::F(n) => format!("F{n}"),
+            _ => String::from("?"),
+        };
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        label + &name
+    }
+}
+
+/// Parses a binding like `q`, `:w`, `<C-z>` or `<C-x><C-s>`.
+pub fn parse(binding: &str) -> Result<Vec<Key>, String> {
+    let mut keys = Vec::new();
+    let mut rest = binding;
+    while let Some(c) = rest.chars().next() {
+        if c == '<' && rest.len() > 1 {
+            let end = rest
+                .find('>')
+                .ok_or_else(|| format!("unclosed '<' in key binding '{binding}'"))?;
+            keys.push(parse_named(&rest[1..end]).ok_or_else(|| {
+                format!("unknown key '{}' in key binding '{binding}'", &rest[..=end])
+            })?);
+            rest = &rest[end + 1..];
+        } else {
+            keys.push(Key {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            });
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if keys.is_empty() {
+        return Err(String::from("empty key binding"));
+    }
+    Ok(keys)
+}
+
+/// The inside of `<...>`: modifiers followed by a key name or character.
+fn parse_named(name: &str) -> Option<Key> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut shift = false;
+    let mut rest = name;
+    while rest.len() > 2 && rest.as_bytes()[1] == b'-' {
+        match rest.as_bytes()[0].to_ascii_uppercase() {
+            b'C' => modifiers |= KeyModifiers::CONTROL,
+            b'M' | b'A' => modifiers |= KeyModifiers::ALT,
+            b'S' => shift = true,
+            _ => return None,
+        }
+        rest = &rest[2..];
+    }
+    let code = match rest.to_ascii_lowercase().as_str() {
+        "enter" | "cr" | "return" => KeyCode::Enter,
+        "tab" if shift => KeyCode::BackTab,
+        "tab" => KeyCode::Tab,
+        "esc" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "bs" | "backspace" => KeyCode::Backspace,
+        "del" => KeyCode::Delete,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "lt" => KeyCode::Char('<'),
+        f if f.len() > 1 && f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+        _ => {
+            let mut chars = rest.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            // Terminals report Ctrl+letter as the lowercase letter.
+            if modifiers.contains(KeyModifiers::CONTROL) {
+                KeyCode::Char(c.to_ascii_lowercase())
+            } else if shift {
+                KeyCode::Char(c.to_ascii_uppercase())
+            } else {
+                KeyCode::Char(c)
+            }
+        }
+    };
+    Some(Key { code, modifiers })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Action {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    /// Choose the highlighted entry, edit the selected field or write,
+    /// depending on the page.
+    Select,
+    Edit,
+    Next,
+    Back,
+    /// Generate from any page.
+    Write,
+    Output,
+    Diff,
+    Undo,
+    Redo,
+    /// Open the command palette.
+    Palette,
+    Quit,
+    /// Store the typed text and go on to the next field.
+    Commit,
+    /// Store the typed text and stop editing.
+    Save,
+    Cancel,
+    Yes,
+    No,
+}
+
+impl Action {
+    pub const ALL: [Action; 20] = [
+        Action::Up,
+        Action::Down,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Select,
+        Action::Edit,
+        Action::Next,
+        Action::Back,
+        Action::Write,
+        Action::Output,
+        Action::Diff,
+        Action::Undo,
+        Action::Redo,
+        Action::Palette,
+        Action::Quit,
+        Action::Commit,
+        Action::Save,
+        Action::Cancel,
+        Action::Yes,
+        Action::No,
+    ];
+
+    /// Name used in the `[keys]` config table.
+    pub fn id(self) -> &'static str {
+        match self {
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::PageUp => "page-up",
+            Action::PageDown => "page-down",
+            Action::Select => "select",
+            Action::Edit => "edit",
+            Action::Next => "next",
+            Action::Back => "back",
+            Action::Write => "write",
+            Action::Output => "output",
+            Action::Diff => "diff",
+            Action::Undo => "undo",
+            Action::Redo => "redo",
+            Action::Palette => "palette",
+            Action::Quit => "quit",
+            Action::Commit => "commit",
+            Action::Save => "save",
+            Action::Cancel => "cancel",
+            Action::Yes => "yes",
+            Action::No => "no",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|action| action.id() == id)
+    }
+}
+
+/// What the UI is doing, which decides the actions keys can trigger.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Context {
+    Navigation,
+    /// Typing into a field or the output path.
+    Text,
+    /// The overwrite question.
+    Confirm,
+    /// Typing a command palette search.
+    Palette,
+}
+
+impl Context {
+    fn actions(self) -> &'static [Action] {
+        match self {
+            Context::Navigation => &[
+                Action::Up,
+                Action::Down,
+                Action::PageUp,
+                Action::PageDown,
+                Action::Select,
+                Action::Edit,
+                Action::Next,
+                Action::Back,
+                Action::Write,
+                Action::Output,
+                Action::Diff,
+                Action::Undo,
+                Action::Redo,
+                Action::Palette,
+                Action::Quit,
+            ],
+            Context::Text => &[
+                Action::Commit,
+                Action::Save,
+                Action::Cancel,
+                Action::Undo,
+                Action::Redo,
+            ],
+            Context::Confirm => &[Action::Yes, Action::No],
+            Context::Palette => &[
+                Action::Up,
+                Action::Down,
+                Action::Commit,
+                Action::Save,
+                Action::Cancel,
+            ],
+        }
+    }
+}
+
+pub enum Resolved {
+    Action(Action),
+    /// The key starts a longer binding; wait for the next one.
+    Pending,
+    /// Not bound in this context.
+    Key(Key),
+}
+
+pub struct Keymap {
+    bindings: Vec<(Action, Vec<Key>)>,
+    /// Keys typed so far of an unfinished sequence.
+    pending: Vec<Key>,
+}
+
+pub const PRESETS: [&str; 3] = ["default", "vim", "emacs"];
+
+impl Default for Keymap {
+    fn default() -> Keymap {
+        Keymap::preset("default").expect("built-in keymap")
+    }
+}
+
+impl Keymap {
+    /// One of [`PRESETS`].
+    pub fn preset(name: &str) -> Option<Keymap> {
+        let table: &[(Action, &[&str])] = match name {
+            "default" => &[
+                (Action::Up, &["<Up>"]),
+                (Action::Down, &["<Down>"]),
+                (Action::PageUp, &["<PageUp>"]),
+                (Action::PageDown, &["<PageDown>"]),
+                (Action::Select, &["<Enter>"]),
+                (Action::Next, &["<Tab>"]),
+                (Action::Back, &["<S-Tab>"]),
+                (Action::Output, &["o"]),
+                (Action::Diff, &["d"]),
+                (Action::Undo, &["<C-z>"]),
+                (Action::Redo, &["<C-y>"]),
+                (Action::Palette, &["<C-p>", "/"]),
+                (Action::Quit, &["q"]),
+                (Action::Commit, &["<Enter>"]),
+                (Action::Cancel, &["<Esc>"]),
+                (Action::Yes, &["y", "Y", "<Enter>"]),
+                (Action::No, &["n", "N", "<Esc>"]),
+            ],
+            "vim" => &[
+                (Action::Up, &["k", "<Up>"]),
+                (Action::Down, &["j", "<Down>"]),
+                (Action::PageUp, &["<C-u>", "<PageUp>"]),
+                (Action::PageDown, &["<C-d>", "<PageDown>"]),
+                (Action::Select, &["<Enter>"]),
+                (Action::Edit, &["i", "a"]),
+                (Action::Next, &["l", "<Tab>"]),
+                (Action::Back, &["h", "<S-Tab>"]),
+                (Action::Write, &[":w", ":x"]),
+                (Action::Output, &["o"]),
+                (Action::Diff, &["d"]),
+                (Action::Undo, &["u"]),
+                (Action::Redo, &["<C-r>"]),
+                (Action::Palette, &["/", "<C-p>"]),
+                (Action::Quit, &[":q", "q"]),
+                (Action::Commit, &["<Enter>"]),
+                (Action::Save, &["<Esc>"]),
+                (Action::Cancel, &["<C-c>"]),
+                (Action::Yes, &["y", "<Enter>"]),
+                (Action::No, &["n", "<Esc>"]),
+            ],
+            "emacs" => &[
+                (Action::Up, &["<C-p>", "<Up>"]),
+                (Action::Down, &["<C-n>", "<Down>"]),
+                (Action::PageUp, &["<M-v>", "<PageUp>"]),
+                (Action::PageDown, &["<C-v>", "<PageDown>"]),
+                (Action::Select, &["<Enter>"]),
+                (Action::Next, &["<C-f>", "<Tab>"]),
+                (Action::Back, &["<C-b>", "<S-Tab>"]),
+                (Action::Write, &["<C-x><C-s>"]),
+                (Action::Output, &["<C-x><C-w>"]),
+                (Action::Diff, &["d"]),
+                // Terminals send C-/ and C-_ as C-7.
+                (Action::Undo, &["<C-x>u", "<C-7>"]),
+                (Action::Redo, &["<C-x>r"]),
+                (Action::Palette, &["<M-x>"]),
+                (Action::Quit, &["<C-x><C-c>"]),
+                (Action::Commit, &["<Enter>"]),
+                (Action::Cancel, &["<C-g>", "<Esc>"]),
+                (Action::Yes, &["y", "<Enter>"]),
+                (Action::No, &["n", "<C-g>", "<Esc>"]),
+            ],
+            _ => return None,
+        };
+        let mut bindings = Vec::new();
+        for &(action, keys) in table {
+            for binding in keys {
+                bindings.push((action, parse(binding).expect("built-in key binding")));
+            }
+        }
+        Some(Keymap {
+            bindings,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Replaces the bindings of `action`.
+    pub fn bind(&mut self, action: Action, bindings: &[String]) -> Result<(), String> {
+        let parsed = bindings
+            .iter()
+            .map(|binding| parse(binding))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.bindings.retain(|(bound, _)| *bound != action);
+        self.bindings.extend(parsed.into_iter().map(|keys| (action, keys)));
+        Ok(())
+    }
+
+    /// The bindings that apply in `context`.
+    fn active(&self, context: Context) -> impl Iterator<Item = &(Action, Vec<Key>)> {
+        self.bindings.iter().filter(move |(action, keys)| {
+            context.actions().contains(action)
+                && !(matches!(context, Context::Text | Context::Palette) && keys[0].is_text())
+        })
+    }
+
+    /// Feeds one key press through the bindings active in `context`.
+    pub fn resolve(&mut self, context: Context, key: Key) -> Resolved {
+        self.pending.push(key);
+        let exact = self
+            .active(context)
+            .find(|(_, keys)| *keys == self.pending)
+            .map(|(action, _)| *action);
+        if let Some(action) = exact {
+            self.pending.clear();
+            return Resolved::Action(action);
+        }
+        if self.active(context).any(|(_, keys)| keys.starts_with(&self.pending)) {
+            return Resolved::Pending;
+        }
+        let abandoned = self.pending.len() > 1;
+        self.pending.clear();
+        if abandoned {
+            // The sequence went nowhere; the last key may start another.
+            return self.resolve(context, key);
+        }
+        Resolved::Key(key)
+    }
+
+    /// Labels of the keys typed so far of an unfinished sequence.
+    pub fn pending(&self) -> String {
+        sequence_label(&self.pending)
+    }
+
+    /// Label of the first binding of `action` that works in `context`.
+    pub fn label(&self, context: Context, action: Action) -> Option<String> {
+        self.active(context)
+            .find(|(bound, _)| *bound == action)
+            .map(|(_, keys)| sequence_label(keys))
+    }
+}
+
+/// `:w` for plain characters, `Ctrl+X Ctrl+S` when named keys are involved.
+fn sequence_label(keys: &[Key]) -> String {
+    if keys.iter().all(|key| key.is_text()) {
+        keys.iter().map(|key| key.label()).collect()
+    } else {
+        keys.iter().map(|key| key.label()).collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_key(c: char, modifiers: KeyModifiers) -> Key {
+        Key {
+            code: KeyCode::Char(c),
+            modifiers,
+        }
+    }
+
+    fn parsed(binding: &str) -> Vec<Key> {
+        parse(binding).unwrap()
+    }
+
+    #[test]
+    fn parse_plain_characters() {
+        assert!(parsed("q") == [char_key('q', KeyModifiers::NONE)]);
+        assert!(parsed(":w") == [char_key(':', KeyModifiers::NONE), char_key('w', KeyModifiers::NONE)]);
+        // A lone `<` is the character itself
+        assert!(parsed("<") == [char_key('<', KeyModifiers::NONE)]);
+        assert!(parsed("<lt>") == [char_key('<', KeyModifiers::NONE)]);
+    }
+
+    #[test]
+    fn parse_named_keys() {
+        let code = |binding| parsed(binding)[0].code;
+        assert!(code("<Enter>") == KeyCode::Enter);
+        assert!(code("<CR>") == KeyCode::Enter);
+        assert!(code("<tab>") == KeyCode::Tab);
+        assert!(code("<S-Tab>") == KeyCode::BackTab);
+        assert!(code("<Space>") == KeyCode::Char(' '));
+        assert!(code("<PageDown>") == KeyCode::PageDown);
+        assert!(code("<F5>") == KeyCode::F(5));
+        assert!(code("<S-a>") == KeyCode::Char('A'));
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        assert!(parsed("<C-z>") == [char_key('z', KeyModifiers::CONTROL)]);
+        // Terminals report Ctrl+letter as the lowercase letter
+        assert!(parsed("<C-Z>") == [char_key('z', KeyModifiers::CONTROL)]);
+        assert!(parsed("<M-x>") == [char_key('x', KeyModifiers::ALT)]);
+        assert!(parsed("<A-x>") == [char_key('x', KeyModifiers::ALT)]);
+        assert!(parsed("<C-M-x>") == [char_key('x', KeyModifiers::CONTROL | KeyModifiers::ALT)]);
+        assert!(parsed("<C-x><C-s>") == [char_key('x', KeyModifiers::CONTROL), char_key('s', KeyModifiers::CONTROL)]);
+        assert!(parsed("<C-x>u") == [char_key('x', KeyModifiers::CONTROL), char_key('u', KeyModifiers::NONE)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse("").err().as_deref(), Some("empty key binding"));
+        assert_eq!(parse("<C-z").err().as_deref(), Some("unclosed '<' in key binding '<C-z'"));
+        assert_eq!(parse("<Foo>").err().as_deref(), Some("unknown key '<Foo>' in key binding '<Foo>'"));
+        assert!(parse("<X-a>").is_err());
+        assert!(parse("<C-ab>").is_err());
+    }
+
+    /// What each key of `keys` resolves to: an action id, `…` for a
+    /// pending sequence or the label of an unbound key.
+    fn feed(keymap: &mut Keymap, context: Context, keys: &str) -> Vec<String> {
+        parse(keys)
+            .unwrap()
+            .into_iter()
+            .map(|key| match keymap.resolve(context, key) {
+                Resolved::Action(action) => action.id().to_string(),
+                Resolved::Pending => String::from("…"),
+                Resolved::Key(key) => key.label(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resolve_single_keys() {
+        let mut keymap = Keymap::default();
+        assert_eq!(feed(&mut keymap, Context::Navigation, "q<Down>x"), ["quit", "down", "x"]);
+        assert_eq!(feed(&mut keymap, Context::Confirm, "y<Esc>q"), ["yes", "no", "q"]);
+    }
+
+    #[test]
+    fn resolve_sequences() {
+        let mut vim = Keymap::preset("vim").unwrap();
+        assert_eq!(feed(&mut vim, Context::Navigation, ":w"), ["…", "write"]);
+        assert_eq!(vim.pending(), "");
+        assert_eq!(feed(&mut vim, Context::Navigation, ":"), ["…"]);
+        assert_eq!(vim.pending(), ":");
+        assert_eq!(feed(&mut vim, Context::Navigation, "q"), ["quit"]);
+
+        let mut emacs = Keymap::preset("emacs").unwrap();
+        assert_eq!(feed(&mut emacs, Context::Navigation, "<C-x><C-s>"), ["…", "write"]);
+        assert_eq!(feed(&mut emacs, Context::Navigation, "<C-x>"), ["…"]);
+        assert_eq!(emacs.pending(), "Ctrl+X");
+    }
+
+    #[test]
+    fn resolve_falls_back_after_a_dead_end() {
+        let mut vim = Keymap::preset("vim").unwrap();
+        // `:j` is no binding, but `j` on its own is
+        assert_eq!(feed(&mut vim, Context::Navigation, ":j"), ["…", "down"]);
+        // `::` restarts the sequence with the second `:`
+        assert_eq!(feed(&mut vim, Context::Navigation, "::w"), ["…", "…", "write"]);
+        // An unbound key after a prefix comes through as a key
+        assert_eq!(feed(&mut vim, Context::Navigation, ":z"), ["…", "z"]);
+        assert_eq!(vim.pending(), "");
+    }
+
+    #[test]
+    fn text_contexts_ignore_plain_character_bindings() {
+        let mut vim = Keymap::preset("vim").unwrap();
+        assert_eq!(feed(&mut vim, Context::Text, "q:w"), ["q", ":", "w"]);
+        assert_eq!(feed(&mut vim, Context::Text, "<C-r><Esc>"), ["redo", "save"]);
+        let mut default = Keymap::default();
+        assert_eq!(feed(&mut default, Context::Palette, "/<Enter>"), ["/", "commit"]);
+    }
+
+    #[test]
+    fn bind_replaces_an_actions_bindings() {
+        let mut keymap = Keymap::default();
+        keymap.bind(Action::Quit, &[String::from("Q"), String::from("<C-q>")]).unwrap();
+        assert_eq!(feed(&mut keymap, Context::Navigation, "qQ<C-q>"), ["q", "quit", "quit"]);
+        assert_eq!(keymap.label(Context::Navigation, Action::Quit).as_deref(), Some("Q"));
+        // A bad binding leaves the old ones in place
+        assert!(keymap.bind(Action::Quit, &[String::from("<Nope>")]).is_err());
+        assert_eq!(feed(&mut keymap, Context::Navigation, "Q"), ["quit"]);
+    }
+}
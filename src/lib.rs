@@ -0,0 +1,39 @@
+//! Generate project READMEs from a handful of fields.
+//!
+//! The `readme_generator` binary is a TUI on top of this crate; the same
+//! renderer is available for tools that want to produce a README directly:
+//!
+//! ```no_run
+//! use readme_generator::Readme;
+//!
+//! let readme = Readme::builder()
+//!     .repository("me/tool")
+//!     .title("Tool")
+//!     .field("short_description", "Does one thing well")
+//!     .list("features", ["Fast", "Small"])
+//!     .license("Apache License 2.0")
+//!     .build()?;
+//!
+//! println!("{}", readme.render());
+//! readme.write_to("README.md")?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod changelog;
+pub mod community;
+pub mod config;
+pub mod diff;
+pub mod document;
+pub mod field;
+pub mod i18n;
+pub mod license;
+pub mod links;
+pub mod lint;
+pub mod messages;
+pub mod output;
+pub mod preset;
+pub mod render;
+pub mod validate;
+
+pub use document::{Readme, ReadmeBuilder};
+pub use field::Field;
@@ -0,0 +1,976 @@
+//! The license catalog, SPDX license expressions and LICENSE files.
+//!
+//! A project's license is an SPDX expression: one id such as `MIT`, or ids
+//! joined by `OR` (pick one) or `AND` (all apply), e.g. `MIT OR Apache-2.0`.
+//! Full license names and the names older versions used, such as "GNU GPL
+//! v3", are accepted in place of ids. One license is written to `LICENSE`;
+//! several go to `LICENSE-<suffix>` each, `LICENSE-MIT` and
+//! `LICENSE-APACHE` for the example. Only the short permissive texts and
+//! Apache-2.0 are bundled; for the others no file is written and the README
+//! links the one the project brings itself.
+//!
+//! License files already in a project are identified by their text, see
+//! [`identify`], so the wizard can start from what is on disk.
+
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct License {
+    /// SPDX identifier.
+    pub id: &'static str,
+    pub name: &'static str,
+    /// File name suffix when several licenses apply, `LICENSE-<suffix>`.
+    pub suffix: &'static str,
+    /// Other names accepted for the license.
+    aliases: &'static [&'static str],
+    /// The license text with `{year}` and `{holder}` to fill in.
+    text: Option<&'static str>,
+}
+
+pub const LICENSES: [License; 22] = [
+    License {
+        id: "MIT",
+        name: "MIT License",
+        suffix: "MIT",
+        aliases: &[],
+        text: Some(MIT),
+    },
+    License {
+        id: "Apache-2.0",
+        name: "Apache License 2.0",
+        suffix: "APACHE",
+        aliases: &[],
+        text: Some(APACHE_2_0),
+    },
+    License {
+        id: "GPL-3.0-only",
+        name: "GNU General Public License v3.0 only",
+        suffix: "GPL",
+        aliases: &["GPL-3.0", "GNU GPL v3"],
+        text: None,
+    },
+    License {
+        id: "GPL-3.0-or-later",
+        name: "GNU General Public License v3.0 or later",
+        suffix: "GPL",
+        aliases: &["GPL-3.0+"],
+        text: None,
+    },
+    License {
+        id: "GPL-2.0-only",
+        name: "GNU General Public License v2.0 only",
+        suffix: "GPL",
+        aliases: &["GPL-2.0"],
+        text: None,
+    },
+    License {
+        id: "GPL-2.0-or-later",
+        name: "GNU General Public License v2.0 or later",
+        suffix: "GPL",
+        aliases: &["GPL-2.0+"],
+        text: None,
+    },
+    License {
+        id: "LGPL-3.0-only",
+        name: "GNU Lesser General Public License v3.0 only",
+        suffix: "LGPL",
+        aliases: &["LGPL-3.0"],
+        text: None,
+    },
+    License {
+        id: "LGPL-3.0-or-later",
+        name: "GNU Lesser General Public License v3.0 or later",
+        suffix: "LGPL",
+        aliases: &["LGPL-3.0+"],
+        text: None,
+    },
+    License {
+        id: "LGPL-2.1-only",
+        name: "GNU Lesser General Public License v2.1 only",
+        suffix: "LGPL",
+        aliases: &["LGPL-2.1"],
+        text: None,
+    },
+    License {
+        id: "LGPL-2.1-or-later",
+        name: "GNU Lesser General Public License v2.1 or later",
+        suffix: "LGPL",
+        aliases: &["LGPL-2.1+"],
+        text: None,
+    },
+    License {
+        id: "AGPL-3.0-only",
+        name: "GNU Affero General Public License v3.0 only",
+        suffix: "AGPL",
+        aliases: &["AGPL-3.0"],
+        text: None,
+    },
+    License {
+        id: "AGPL-3.0-or-later",
+        name: "GNU Affero General Public License v3.0 or later",
+        suffix: "AGPL",
+        aliases: &["AGPL-3.0+"],
+        text: None,
+    },
+    License {
+        id: "MPL-2.0",
+        name: "Mozilla Public License 2.0",
+        suffix: "MPL",
+        aliases: &[],
+        text: None,
+    },
+    License {
+        id: "BSD-2-Clause",
+        name: "BSD 2-Clause \"Simplified\" License",
+        suffix: "BSD",
+        aliases: &["BSD 2-Clause"],
+        text: Some(BSD_2_CLAUSE),
+    },
+    License {
+        id: "BSD-3-Clause",
+        name: "BSD 3-Clause \"New\" or \"Revised\" License",
+        suffix: "BSD",
+        aliases: &["BSD 3-Clause"],
+        text: Some(BSD_3_CLAUSE),
+    },
+    License {
+        id: "ISC",
+        name: "ISC License",
+        suffix: "ISC",
+        aliases: &[],
+        text: Some(ISC),
+    },
+    License {
+        id: "0BSD",
+        name: "BSD Zero Clause License",
+        suffix: "0BSD",
+        aliases: &[],
+        text: Some(ZERO_BSD),
+    },
+    License {
+        id: "Unlicense",
+        name: "The Unlicense",
+        suffix: "UNLICENSE",
+        aliases: &[],
+        text: Some(UNLICENSE),
+    },
+    License {
+        id: "CC0-1.0",
+        name: "Creative Commons Zero v1.0 Universal",
+        suffix: "CC0",
+        aliases: &[],
+        text: None,
+    },
+    License {
+        id: "BSL-1.0",
+        name: "Boost Software License 1.0",
+        suffix: "BOOST",
+        aliases: &[],
+        text: Some(BSL_1_0),
+    },
+    License {
+        id: "EUPL-1.2",
+        name: "European Union Public License 1.2",
+        suffix: "EUPL",
+        aliases: &[],
+        text: None,
+    },
+    License {
+        id: "Zlib",
+        name: "zlib License",
+        suffix: "ZLIB",
+        aliases: &[],
+        text: Some(ZLIB),
+    },
+];
+
+impl License {
+    /// Looks a license up by SPDX id, name or alias, ignoring case.
+    pub fn find(name: &str) -> Option<&'static License> {
+        let name = name.trim();
+        LICENSES.iter().find(|license| {
+            license.id.eq_ignore_ascii_case(name)
+                || license.name.eq_ignore_ascii_case(name)
+                || license.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Whether the two are the same license or differ only in `-only` and
+    /// `-or-later`, which their license files do not tell apart.
+    pub fn same_family(&self, other: &License) -> bool {
+        let family = |id: &'static str| {
+            id.strip_suffix("-only")
+                .or_else(|| id.strip_suffix("-or-later"))
+                .unwrap_or(id)
+        };
+        family(self.id) == family(other.id)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operator {
+    /// Any one of the licenses, at the user's option.
+    Or,
+    /// All of the licenses at once.
+    And,
+}
+
+pub struct Expression {
+    pub licenses: Vec<&'static License>,
+    pub operator: Operator,
+}
+
+impl Expression {
+    /// Parses `MIT`, `MIT OR Apache-2.0` and the like. As in SPDX, the
+    /// operators are upper case, which keeps names such as "GNU General
+    /// Public License v3.0 or later" in one piece. Mixing `OR` and `AND`
+    /// needs parentheses, which are not supported, and so are `WITH`
+    /// exceptions. The deprecated `/`
+    /// separator, as in `MIT/Apache-2.0`, reads as `OR`.
+    pub fn parse(text: &str) -> Result<Expression, String> {
+        let normalized = text.replace('/', " OR ");
+        let mut inner = normalized.trim();
+        if let Some(stripped) = inner.strip_prefix('(').and_then(|rest| rest.strip_suffix(')')) {
+            inner = stripped.trim();
+        }
+        if inner.contains(['(', ')']) {
+            return Err(format!("license expression '{text}': nested parentheses are not supported"));
+        }
+        if inner.split_whitespace().any(|word| word == "WITH") {
+            return Err(format!("license expression '{text}': exceptions (WITH) are not supported"));
+        }
+
+        let mut operator = None;
+        let mut terms = vec![Vec::new()];
+        for word in inner.split_whitespace() {
+            let op = match word {
+                "OR" => Operator::Or,
+                "AND" => Operator::And,
+                _ => {
+                    terms.last_mut().expect("term").push(word);
+                    continue;
+                }
+            };
+            if operator.is_some_and(|operator| operator != op) {
+                return Err(format!("license expression '{text}' mixes OR and AND"));
+            }
+            operator = Some(op);
+            terms.push(Vec::new());
+        }
+
+        let mut licenses: Vec<&'static License> = Vec::new();
+        for term in terms {
+            let name = term.join(" ");
+            let license = License::find(&name).ok_or_else(|| {
+                if name.is_empty() {
+                    format!("license expression '{text}' is incomplete")
+                } else {
+                    format!("unknown license '{name}'")
+                }
+            })?;
+            if !licenses.iter().any(|known| known.id == license.id) {
+                licenses.push(license);
+            }
+        }
+        Ok(Expression {
+            licenses,
+            operator: operator.unwrap_or(Operator::Or),
+        })
+    }
+
+    /// The expression in SPDX form, e.g. `MIT OR Apache-2.0`.
+    pub fn id(&self) -> String {
+        let separator = match self.operator {
+            Operator::Or => " OR ",
+            Operator::And => " AND ",
+        };
+        let ids: Vec<&str> = self.licenses.iter().map(|license| license.id).collect();
+        ids.join(separator)
+    }
+
+    /// Whether both name the same licenses, in any order.
+    pub fn same_licenses(&self, other: &Expression) -> bool {
+        self.matches(other, |a, b| a.id == b.id)
+    }
+
+    /// Like [`Expression::same_licenses`], but `-only` and `-or-later` of
+    /// a license count as the same, see [`License::same_family`].
+    pub fn same_families(&self, other: &Expression) -> bool {
+        self.matches(other, License::same_family)
+    }
+
+    fn matches(&self, other: &Expression, same: impl Fn(&License, &License) -> bool) -> bool {
+        let covers = |a: &Expression, b: &Expression| {
+            a.licenses.iter().all(|license| b.licenses.iter().any(|other| same(license, other)))
+        };
+        covers(self, other) && covers(other, self)
+    }
+
+    /// Whether both name the same licenses combined the same way.
+    pub fn same_as(&self, other: &Expression) -> bool {
+        self.same_licenses(other) && (self.licenses.len() == 1 || self.operator == other.operator)
+    }
+
+    /// Name of the file each license goes to, in the order of `licenses`.
+    pub fn file_names(&self) -> Vec<String> {
+        if self.licenses.len() == 1 {
+            return vec![String::from("LICENSE")];
+        }
+        let mut names: Vec<String> = Vec::new();
+        for license in &self.licenses {
+            let mut name = format!("LICENSE-{}", license.suffix);
+            // GPL-2.0-only OR GPL-3.0-only share a suffix
+            if names.contains(&name) {
+                name = format!("LICENSE-{}", license.id.to_ascii_uppercase());
+            }
+            names.push(name);
+        }
+        names
+    }
+
+    /// File names and texts of the licenses that have a bundled text, the
+    /// copyright line made out to `holder`.
+    pub fn files(&self, holder: &str, year: i32) -> Vec<(String, String)> {
+        self.licenses
+            .iter()
+            .zip(self.file_names())
+            .filter_map(|(license, file)| {
+                let text = license.text?;
+                Some((file, text.replace("{year}", &year.to_string()).replace("{holder}", holder)))
+            })
+            .collect()
+    }
+}
+
+/// Headings of the licenses without a bundled text, lower case with
+/// punctuation dropped. More specific headings come first: the LGPL and
+/// AGPL mention the GPL. A file cannot tell `-only` from `-or-later`.
+const HEADINGS: [(&str, &str); 8] = [
+    ("gnu affero general public license version 3", "AGPL-3.0-only"),
+    ("gnu lesser general public license version 3", "LGPL-3.0-only"),
+    ("gnu lesser general public license version 2 1", "LGPL-2.1-only"),
+    ("gnu general public license version 3", "GPL-3.0-only"),
+    ("gnu general public license version 2", "GPL-2.0-only"),
+    ("mozilla public license version 2 0", "MPL-2.0"),
+    ("cc0 1 0 universal", "CC0-1.0"),
+    ("european union public licence v 1 2", "EUPL-1.2"),
+];
+
+/// Lower case words separated by single spaces, so wrapping, punctuation,
+/// typographic quotes, `https` for `http` and list markers such as `1.` or
+/// `*` do not get in the way of a comparison.
+fn normalize(text: &str) -> String {
+    let words: Vec<String> = text
+        .lines()
+        .map(|line| {
+            let line = line.trim_start();
+            let number = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            match line[number..].strip_prefix(['.', ')']) {
+                Some(rest) if number > 0 && rest.starts_with(' ') => rest,
+                _ => line,
+            }
+        })
+        .flat_map(|line| line.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(|word| match word.to_lowercase() {
+            word if word == "https" => String::from("http"),
+            word => word,
+        })
+        .collect();
+    words.join(" ")
+}
+
+/// Which license `text` is. A bundled license matches when every paragraph
+/// of its text appears in `text`, leaving out titles, the copyright line and
+/// Apache's appendix, and makes up at least half of it, so a long license
+/// quoting a short one is not taken for it. When several match, as
+/// BSD-2-Clause does within BSD-3-Clause, the longest wins. The others are
+/// recognized by their heading.
+pub fn identify(text: &str) -> Option<&'static License> {
+    let haystack = normalize(text);
+    let bundled = LICENSES
+        .iter()
+        .filter_map(|license| {
+            let template = license.text?.split("END OF TERMS AND CONDITIONS").next()?;
+            let paragraphs: Vec<String> = template
+                .split("\n\n")
+                .filter(|paragraph| !paragraph.contains("{year}") && !paragraph.contains("{holder}"))
+                .map(normalize)
+                .filter(|paragraph| paragraph.split(' ').count() >= 5)
+                .collect();
+            let matched: usize = paragraphs.iter().map(String::len).sum();
+            let found = paragraphs.iter().all(|paragraph| haystack.contains(paragraph.as_str()));
+            (found && matched * 2 >= haystack.len()).then_some((matched, license))
+        })
+        .max_by_key(|(matched, _)| *matched)
+        .map(|(_, license)| license);
+    bundled.or_else(|| {
+        let head: Vec<&str> = haystack.split(' ').take(40).collect();
+        let head = head.join(" ");
+        HEADINGS
+            .iter()
+            .find(|(heading, _)| head.contains(heading))
+            .and_then(|(_, id)| License::find(id))
+    })
+}
+
+/// Whether `name` is a file projects keep a license in: `LICENSE`,
+/// `LICENSE.md`, `LICENSE-MIT`, `COPYING`, `UNLICENSE` and the like.
+fn is_license_file(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    ["LICENSE", "LICENCE", "COPYING", "UNLICENSE"]
+        .iter()
+        .any(|prefix| upper.starts_with(prefix))
+        && !upper.ends_with(".BAK")
+}
+
+/// The license files in `dir` that could be identified, by file name.
+pub fn detect(dir: &Path) -> Vec<(String, &'static License)> {
+    let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<(String, &'static License)> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !is_license_file(&name) {
+                return None;
+            }
+            let text = fs::read_to_string(entry.path()).ok()?;
+            Some((name, identify(&text)?))
+        })
+        .collect();
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    found
+}
+
+/// The `license` Cargo.toml in `dir` declares for its package, if any.
+pub fn cargo_license(dir: &Path) -> Option<String> {
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let manifest: toml::Table = toml::from_str(&manifest).ok()?;
+    let license = manifest.get("package")?.get("license")?.as_str()?;
+    Some(license.to_string())
+}
+
+/// The current year in UTC, for copyright lines.
+pub fn current_year() -> i32 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    // Days since 1970-01-01 to a civil year, after Howard Hinnant's
+    // `civil_from_days`, with years starting in March
+    let days = secs.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let january_or_february = month_index >= 10;
+    (year_of_era + era * 400 + i64::from(january_or_february)) as i32
+}
+
+const MIT: &str = "\
+MIT License
+
+Copyright (c) {year} {holder}
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+";
+
+const ISC: &str = "\
+ISC License
+
+Copyright (c) {year} {holder}
+
+Permission to use, copy, modify, and/or distribute this software for any
+purpose with or without fee is hereby granted, provided that the above
+copyright notice and this permission notice appear in all copies.
+
+THE SOFTWARE IS PROVIDED \"AS IS\" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+";
+
+const ZERO_BSD: &str = "\
+Copyright (C) {year} by {holder}
+
+Permission to use, copy, modify, and/or distribute this software for any
+purpose with or without fee is hereby granted.
+
+THE SOFTWARE IS PROVIDED \"AS IS\" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+";
+
+const BSD_2_CLAUSE: &str = "\
+BSD 2-Clause License
+
+Copyright (c) {year}, {holder}
+
+Redistribution and use in source and binary forms, with or without
+modification, are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\"
+AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+";
+
+const BSD_3_CLAUSE: &str = "\
+BSD 3-Clause License
+
+Copyright (c) {year}, {holder}
+
+Redistribution and use in source and binary forms, with or without
+modification, are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its
+   contributors may be used to endorse or promote products derived from
+   this software without specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\"
+AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+";
+
+const UNLICENSE: &str = "\
+This is free and unencumbered software released into the public domain.
+
+Anyone is free to copy, modify, publish, use, compile, sell, or
+distribute this software, either in source code form or as a compiled
+binary, for any purpose, commercial or non-commercial, and by any
+means.
+
+In jurisdictions that recognize copyright laws, the author or authors
+of this software dedicate any and all copyright interest in the
+software to the public domain. We make this dedication for the benefit
+of the public at large and to the detriment of our heirs and
+successors. We intend this dedication to be an overt act of
+relinquishment in perpetuity of all present and future rights to this
+software under copyright law.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND,
+EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+OTHER DEALINGS IN THE SOFTWARE.
+
+For more information, please refer to <https://unlicense.org>
+";
+
+const BSL_1_0: &str = "\
+Boost Software License - Version 1.0 - August 17th, 2003
+
+Permission is hereby granted, free of charge, to any person or organization
+obtaining a copy of the software and accompanying documentation covered by
+this license (the \"Software\") to use, reproduce, display, distribute,
+execute, and transmit the Software, and to prepare derivative works of the
+Software, and to permit third-parties to whom the Software is furnished to
+do so, all subject to the following:
+
+The copyright notices in the Software and this entire statement, including
+the above license grant, this restriction and the following disclaimer,
+must be included in all copies of the Software, in whole or in part, and
+all derivative works of the Software, unless such copies or derivative
+works are solely in the form of machine-executable object code generated by
+a source language processor.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE, TITLE AND NON-INFRINGEMENT. IN NO EVENT
+SHALL THE COPYRIGHT HOLDERS OR ANYONE DISTRIBUTING THE SOFTWARE BE LIABLE
+FOR ANY DAMAGES OR OTHER LIABILITY, WHETHER IN CONTRACT, TORT OR OTHERWISE,
+ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+DEALINGS IN THE SOFTWARE.
+";
+
+const ZLIB: &str = "\
+zlib License
+
+Copyright (c) {year} {holder}
+
+This software is provided 'as-is', without any express or implied
+warranty. In no event will the authors be held liable for any damages
+arising from the use of this software.
+
+Permission is granted to anyone to use this software for any purpose,
+including commercial applications, and to alter it and redistribute it
+freely, subject to the following restrictions:
+
+1. The origin of this software must not be misrepresented; you must not
+   claim that you wrote the original software. If you use this software
+   in a product, an acknowledgment in the product documentation would be
+   appreciated but is not required.
+2. Altered source versions must be plainly marked as such, and must not be
+   misrepresented as being the original software.
+3. This notice may not be removed or altered from any source distribution.
+";
+
+const APACHE_2_0: &str = "
+                                 Apache License
+                           Version 2.0, January 2004
+                        http://www.apache.org/licenses/
+
+   TERMS AND CONDITIONS FOR USE, REPRODUCTION, AND DISTRIBUTION
+
+   1. Definitions.
+
+      \"License\" shall mean the terms and conditions for use, reproduction,
+      and distribution as defined by Sections 1 through 9 of this document.
+
+      \"Licensor\" shall mean the copyright owner or entity authorized by
+      the copyright owner that is granting the License.
+
+      \"Legal Entity\" shall mean the union of the acting entity and all
+      other entities that control, are controlled by, or are under common
+      control with that entity. For the purposes of this definition,
+      \"control\" means (i) the power, direct or indirect, to cause the
+      direction or management of such entity, whether by contract or
+      otherwise, or (ii) ownership of fifty percent (50%) or more of the
+      outstanding shares, or (iii) beneficial ownership of such entity.
+
+      \"You\" (or \"Your\") shall mean an individual or Legal Entity
+      exercising permissions granted by this License.
+
+      \"Source\" form shall mean the preferred form for making modifications,
+      including but not limited to software source code, documentation
+      source, and configuration files.
+
+      \"Object\" form shall mean any form resulting from mechanical
+      transformation or translation of a Source form, including but
+      not limited to compiled object code, generated documentation,
+      and conversions to other media types.
+
+      \"Work\" shall mean the work of authorship, whether in Source or
+      Object form, made available under the License, as indicated by a
+      copyright notice that is included in or attached to the work
+      (an example is provided in the Appendix below).
+
+      \"Derivative Works\" shall mean any work, whether in Source or Object
+      form, that is based on (or derived from) the Work and for which the
+      editorial revisions, annotations, elaborations, or other modifications
+      represent, as a whole, an original work of authorship. For the purposes
+      of this License, Derivative Works shall not include works that remain
+      separable from, or merely link (or bind by name) to the interfaces of,
+      the Work and Derivative Works thereof.
+
+      \"Contribution\" shall mean any work of authorship, including
+      the original version of the Work and any modifications or additions
+      to that Work or Derivative Works thereof, that is intentionally
+      submitted to Licensor for inclusion in the Work by the copyright owner
+      or by an individual or Legal Entity authorized to submit on behalf of
+      the copyright owner. For the purposes of this definition, \"submitted\"
+      means any form of electronic, verbal, or written communication sent
+      to the Licensor or its representatives, including but not limited to
+      communication on electronic mailing lists, source code control systems,
+      and issue tracking systems that are managed by, or on behalf of, the
+      Licensor for the purpose of discussing and improving the Work, but
+      excluding communication that is conspicuously marked or otherwise
+      designated in writing by the copyright owner as \"Not a Contribution.\"
+
+      \"Contributor\" shall mean Licensor and any individual or Legal Entity
+      on behalf of whom a Contribution has been received by Licensor and
+      subsequently incorporated within the Work.
+
+   2. Grant of Copyright License. Subject to the terms and conditions of
+      this License, each Contributor hereby grants to You a perpetual,
+      worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+      copyright license to reproduce, prepare Derivative Works of,
+      publicly display, publicly perform, sublicense, and distribute the
+      Work and such Derivative Works in Source or Object form.
+
+   3. Grant of Patent License. Subject to the terms and conditions of
+      this License, each Contributor hereby grants to You a perpetual,
+      worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+      (except as stated in this section) patent license to make, have made,
+      use, offer to sell, sell, import, and otherwise transfer the Work,
+      where such license applies only to those patent claims licensable
+      by such Contributor that are necessarily infringed by their
+      Contribution(s) alone or by combination of their Contribution(s)
+      with the Work to which such Contribution(s) was submitted. If You
+      institute patent litigation against any entity (including a
+      cross-claim or counterclaim in a lawsuit) alleging that the Work
+      or a Contribution incorporated within the Work constitutes direct
+      or contributory patent infringement, then any patent licenses
+      granted to You under this License for that Work shall terminate
+      as of the date such litigation is filed.
+
+   4. Redistribution. You may reproduce and distribute copies of the
+      Work or Derivative Works thereof in any medium, with or without
+      modifications, and in Source or Object form, provided that You
+      meet the following conditions:
+
+      (a) You must give any other recipients of the Work or
+          Derivative Works a copy of this License; and
+
+      (b) You must cause any modified files to carry prominent notices
+          stating that You changed the files; and
+
+      (c) You must retain, in the Source form of any Derivative Works
+          that You distribute, all copyright, patent, trademark, and
+          attribution notices from the Source form of the Work,
+          excluding those notices that do not pertain to any part of
+          the Derivative Works; and
+
+      (d) If the Work includes a \"NOTICE\" text file as part of its
+          distribution, then any Derivative Works that You distribute must
+          include a readable copy of the attribution notices contained
+          within such NOTICE file, excluding those notices that do not
+          pertain to any part of the Derivative Works, in at least one
+          of the following places: within a NOTICE text file distributed
+          as part of the Derivative Works; within the Source form or
+          documentation, if provided along with the Derivative Works; or,
+          within a display generated by the Derivative Works, if and
+          wherever such third-party notices normally appear. The contents
+          of the NOTICE file are for informational purposes only and
+          do not modify the License. You may add Your own attribution
+          notices within Derivative Works that You distribute, alongside
+          or as an addendum to the NOTICE text from the Work, provided
+          that such additional attribution notices cannot be construed
+          as modifying the License.
+
+      You may add Your own copyright statement to Your modifications and
+      may provide additional or different license terms and conditions
+      for use, reproduction, or distribution of Your modifications, or
+      for any such Derivative Works as a whole, provided Your use,
+      reproduction, and distribution of the Work otherwise complies with
+      the conditions stated in this License.
+
+   5. Submission of Contributions. Unless You explicitly state otherwise,
+      any Contribution intentionally submitted for inclusion in the Work
+      by You to the Licensor shall be under the terms and conditions of
+      this License, without any additional terms or conditions.
+      Notwithstanding the above, nothing herein shall supersede or modify
+      the terms of any separate license agreement you may have executed
+      with Licensor regarding such Contributions.
+
+   6. Trademarks. This License does not grant permission to use the trade
+      names, trademarks, service marks, or product names of the Licensor,
+      except as required for reasonable and customary use in describing the
+      origin of the Work and reproducing the content of the NOTICE file.
+
+   7. Disclaimer of Warranty. Unless required by applicable law or
+      agreed to in writing, Licensor provides the Work (and each
+      Contributor provides its Contributions) on an \"AS IS\" BASIS,
+      WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or
+      implied, including, without limitation, any warranties or conditions
+      of TITLE, NON-INFRINGEMENT, MERCHANTABILITY, or FITNESS FOR A
+      PARTICULAR PURPOSE. You are solely responsible for determining the
+      appropriateness of using or redistributing the Work and assume any
+      risks associated with Your exercise of permissions under this License.
+
+   8. Limitation of Liability. In no event and under no legal theory,
+      whether in tort (including negligence), contract, or otherwise,
+      unless required by applicable law (such as deliberate and grossly
+      negligent acts) or agreed to in writing, shall any Contributor be
+      liable to You for damages, including any direct, indirect, special,
+      incidental, or consequential damages of any character arising as a
+      result of this License or out of the use or inability to use the
+      Work (including but not limited to damages for loss of goodwill,
+      work stoppage, computer failure or malfunction, or any and all
+      other commercial damages or losses), even if such Contributor
+      has been advised of the possibility of such damages.
+
+   9. Accepting Warranty or Additional Liability. While redistributing
+      the Work or Derivative Works thereof, You may choose to offer,
+      and charge a fee for, acceptance of support, warranty, indemnity,
+      or other liability obligations and/or rights consistent with this
+      License. However, in accepting such obligations, You may act only
+      on Your own behalf and on Your sole responsibility, not on behalf
+      of any other Contributor, and only if You agree to indemnify,
+      defend, and hold each Contributor harmless for any liability
+      incurred by, or claims asserted against, such Contributor by reason
+      of your accepting any such warranty or additional liability.
+
+   END OF TERMS AND CONDITIONS
+
+   Copyright {year} {holder}
+
+   Licensed under the Apache License, Version 2.0 (the \"License\");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an \"AS IS\" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(text: &str) -> Result<(Vec<&'static str>, bool), String> {
+        Expression::parse(text).map(|expression| {
+            let ids = expression.licenses.iter().map(|license| license.id).collect();
+            (ids, expression.operator == Operator::Or)
+        })
+    }
+
+    #[test]
+    fn parse_single() {
+        assert_eq!(ids("MIT"), Ok((vec!["MIT"], true)));
+        assert_eq!(ids(" mit "), Ok((vec!["MIT"], true)));
+        assert_eq!(ids("Apache License 2.0"), Ok((vec!["Apache-2.0"], true)));
+        assert_eq!(ids("GNU GPL v3"), Ok((vec!["GPL-3.0-only"], true)));
+        for id in ["GPL-2.0-or-later", "LGPL-2.1-or-later", "LGPL-3.0-or-later", "AGPL-3.0-or-later"] {
+            assert_eq!(ids(id), Ok((vec![id], true)));
+        }
+        assert_eq!(ids("LGPL-2.1+"), Ok((vec!["LGPL-2.1-or-later"], true)));
+        // Lower case `or` is part of the name, not an operator
+        assert_eq!(ids("GNU General Public License v3.0 or later"), Ok((vec!["GPL-3.0-or-later"], true)));
+    }
+
+    #[test]
+    fn parse_operators() {
+        assert_eq!(ids("MIT OR Apache-2.0"), Ok((vec!["MIT", "Apache-2.0"], true)));
+        assert_eq!(ids("(MIT OR Apache-2.0)"), Ok((vec!["MIT", "Apache-2.0"], true)));
+        assert_eq!(ids("MIT AND ISC"), Ok((vec!["MIT", "ISC"], false)));
+        assert_eq!(ids("MIT OR MIT"), Ok((vec!["MIT"], true)));
+        assert_eq!(Expression::parse("Apache-2.0 OR MIT").unwrap().id(), "Apache-2.0 OR MIT");
+    }
+
+    #[test]
+    fn parse_slash_is_or() {
+        assert_eq!(ids("MIT/Apache-2.0"), Ok((vec!["MIT", "Apache-2.0"], true)));
+        assert_eq!(ids("MIT / Apache-2.0"), Ok((vec!["MIT", "Apache-2.0"], true)));
+        assert_eq!(Expression::parse("MIT/Apache-2.0").unwrap().id(), "MIT OR Apache-2.0");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Expression::parse("MIT OR Apache-2.0 AND ISC").is_err());
+        assert!(Expression::parse("MIT OR (Apache-2.0 AND ISC)").is_err());
+        assert!(Expression::parse("MIT OR").is_err());
+        assert!(Expression::parse("").is_err());
+        assert!(Expression::parse("Proprietary").is_err());
+        assert_eq!(
+            Expression::parse("Apache-2.0 WITH LLVM-exception").err().as_deref(),
+            Some("license expression 'Apache-2.0 WITH LLVM-exception': exceptions (WITH) are not supported")
+        );
+    }
+
+    #[test]
+    fn file_names() {
+        let names = |text| Expression::parse(text).unwrap().file_names();
+        assert_eq!(names("MIT"), ["LICENSE"]);
+        assert_eq!(names("MIT OR Apache-2.0"), ["LICENSE-MIT", "LICENSE-APACHE"]);
+        assert_eq!(names("GPL-2.0-only OR GPL-3.0-only"), ["LICENSE-GPL", "LICENSE-GPL-3.0-ONLY"]);
+    }
+
+    #[test]
+    fn families() {
+        let find = |id| License::find(id).unwrap();
+        assert!(find("GPL-3.0-only").same_family(find("GPL-3.0-or-later")));
+        assert!(!find("GPL-3.0-only").same_family(find("GPL-2.0-only")));
+        assert!(!find("GPL-3.0-only").same_family(find("LGPL-3.0-only")));
+        let parse = |text| Expression::parse(text).unwrap();
+        assert!(parse("GPL-3.0-or-later OR MIT").same_families(&parse("MIT OR GPL-3.0-only")));
+        assert!(!parse("GPL-3.0-or-later").same_licenses(&parse("GPL-3.0-only")));
+        assert!(!parse("GPL-3.0-or-later").same_families(&parse("GPL-3.0-only OR MIT")));
+    }
+
+    fn filled(id: &str) -> String {
+        let expression = Expression::parse(id).unwrap();
+        expression.files("Jane Doe", 2024).remove(0).1
+    }
+
+    #[test]
+    fn identify_bundled_texts() {
+        for license in LICENSES.iter().filter(|license| license.text.is_some()) {
+            let found = identify(&filled(license.id)).map(|found| found.id);
+            assert_eq!(found, Some(license.id));
+        }
+    }
+
+    #[test]
+    fn identify_ignores_wrapping_and_markup() {
+        // Rewrapped, Markdown heading, `*` bullets and typographic quotes
+        let text = filled("BSD-3-Clause")
+            .replace("BSD 3-Clause License", "# BSD 3-Clause License")
+            .replace("1. ", "* ")
+            .replace("2. ", "* ")
+            .replace("3. ", "* ")
+            .replace("\"AS IS\"", "“AS IS”")
+            .replace('\n', " ");
+        assert_eq!(identify(&text).map(|license| license.id), Some("BSD-3-Clause"));
+        // BSD-2-Clause is contained in BSD-3-Clause but is its own license
+        assert_eq!(identify(&filled("BSD-2-Clause")).map(|license| license.id), Some("BSD-2-Clause"));
+    }
+
+    #[test]
+    fn identify_by_heading() {
+        let gpl = "GNU GENERAL PUBLIC LICENSE\nVersion 3, 29 June 2007\n\nCopyright (C) 2007 Free Software Foundation";
+        assert_eq!(identify(gpl).map(|license| license.id), Some("GPL-3.0-only"));
+        let lgpl = "GNU LESSER GENERAL PUBLIC LICENSE\nVersion 3, 29 June 2007\n\nThis version of the GNU Lesser General Public License incorporates the GNU General Public License version 3";
+        assert_eq!(identify(lgpl).map(|license| license.id), Some("LGPL-3.0-only"));
+    }
+
+    #[test]
+    fn identify_rejects_other_text() {
+        assert!(identify("All rights reserved.").is_none());
+        // A long document quoting a short license is not that license
+        let mut long = filled("MIT");
+        long.push_str(&"Other terms apply to everything else in this distribution. ".repeat(100));
+        assert!(identify(&long).is_none());
+    }
+}
@@ -0,0 +1,186 @@
+//! Offline checker for relative links and images in a README.
+//!
+//! Every relative link or image is resolved against the directory the README
+//! lives in; links into other local Markdown files also have their
+//! `#fragment` checked against that file's headings. Fragment-only links
+//! within the document itself are lint rule MD051.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::lint::{self, Issue};
+
+/// URLs with a scheme (`https:`, `mailto:`, `data:` …) or protocol-relative
+/// ones are never looked up on disk.
+fn is_external(target: &str) -> bool {
+    target.starts_with("//")
+        || target.split_once(':').is_some_and(|(scheme, _)| {
+            !scheme.is_empty()
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '.' || c == '-')
+        })
+}
+
+fn percent_decode(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            if let Ok(byte) = u8::from_str_radix(&raw[i + 1..i + 3], 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Where a relative `target` points on disk, ignoring query and fragment.
+fn resolve(base: &Path, target: &str) -> PathBuf {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    // GitHub resolves a leading slash against the repository root, which is
+    // the directory holding the README for our purposes.
+    base.join(percent_decode(path.trim_start_matches('/')))
+}
+
+fn is_markdown(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("md" | "markdown" | "mdown")
+    )
+}
+
+pub fn check(markdown: &str, base: &Path) -> Vec<Issue> {
+    check_planned(markdown, base, &[])
+}
+
+/// Like [`check`], counting the files in `planned` as existing because
+/// they are about to be written along with the README.
+pub fn check_planned(markdown: &str, base: &Path, planned: &[PathBuf]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for link in lint::links(markdown) {
+        let target = link.target.as_str();
+        if target.is_empty() || target.starts_with('#') || is_external(target) {
+            continue;
+        }
+
+        let path = resolve(base, target);
+        if planned.contains(&path) {
+            continue;
+        }
+        if !path.exists() {
+            issues.push(Issue {
+                line: link.line,
+                rule: if link.is_image { "missing-image" } else { "missing-file" },
+                message: format!("{} does not exist", path.display()),
+            });
+            continue;
+        }
+
+        let Some((_, fragment)) = target.split_once('#') else {
+            continue;
+        };
+        if fragment.is_empty() || !path.is_file() || !is_markdown(&path) {
+            continue;
+        }
+        if let Ok(contents) = fs::read_to_string(&path) {
+            let anchors = lint::document_anchors(&contents);
+            if !anchors.contains(fragment) && !anchors.contains(&fragment.to_lowercase()) {
+                issues.push(Issue {
+                    line: link.line,
+                    rule: "missing-anchor",
+                    message: format!("{} has no heading for #{fragment}", path.display()),
+                });
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// A fresh directory under the system temp dir for one test.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("readmegen-links-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn rules(issues: &[Issue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.rule).collect()
+    }
+
+    #[test]
+    fn external_targets() {
+        assert!(is_external("https://example.com"));
+        assert!(is_external("mailto:me@example.com"));
+        assert!(is_external("data:image/png;base64,AAAA"));
+        assert!(is_external("git+ssh://host/repo"));
+        assert!(is_external("//cdn.example.com/logo.png"));
+        assert!(!is_external("docs/guide.md"));
+        assert!(!is_external("/LICENSE"));
+        assert!(!is_external(":colon.md"));
+        assert!(!is_external("a b:c.md"));
+    }
+
+    #[test]
+    fn percent_decoding() {
+        assert_eq!(percent_decode("My%20Guide.md"), "My Guide.md");
+        assert_eq!(percent_decode("caf%C3%A9.md"), "café.md");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+    }
+
+    #[test]
+    fn missing_files_and_images() {
+        let dir = temp_dir("missing");
+        fs::write(dir.join("LICENSE"), "MIT").unwrap();
+        fs::create_dir(dir.join("docs")).unwrap();
+        fs::write(dir.join("docs/My Guide.md"), "# Guide\n").unwrap();
+        let markdown = "[License](LICENSE)\n\n[Root](/LICENSE)\n\n[Guide](docs/My%20Guide.md?plain=1)\n\n\
+                        [Gone](docs/gone.md)\n\n![Logo](logo.png)\n\n[Web](https://example.com/x.md)\n";
+        let issues = check(markdown, &dir);
+        assert_eq!(rules(&issues), ["missing-file", "missing-image"]);
+        assert_eq!(issues[0].line, 7);
+        assert_eq!(issues[1].line, 9);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn planned_files_count_as_existing() {
+        let dir = temp_dir("planned");
+        let markdown = "[Contributing](CONTRIBUTING.md#running-the-tests)\n\n[Changelog](/CHANGELOG.md)\n";
+        assert_eq!(rules(&check(markdown, &dir)), ["missing-file", "missing-file"]);
+        let planned = [dir.join("CONTRIBUTING.md"), dir.join("CHANGELOG.md")];
+        assert!(check_planned(markdown, &dir, &planned).is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fragments_into_other_markdown_files() {
+        let dir = temp_dir("fragments");
+        fs::write(dir.join("GUIDE.md"), "# Guide\n\n## Getting Started\n").unwrap();
+        fs::write(dir.join("notes.txt"), "plain text").unwrap();
+        let markdown = "[Start](GUIDE.md#getting-started)\n\n[Upper](GUIDE.md#Getting-Started)\n\n\
+                        [Top](GUIDE.md#)\n\n[Text](notes.txt#anything)\n\n[Nowhere](GUIDE.md#installation)\n";
+        let issues = check(markdown, &dir);
+        assert_eq!(rules(&issues), ["missing-anchor"]);
+        assert_eq!(issues[0].line, 9);
+        assert!(issues[0].message.ends_with("has no heading for #installation"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
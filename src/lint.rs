@@ -0,0 +1,573 @@
+//! A small Markdown linter for the generated README.
+//!
+//! It implements the markdownlint rules that the generator is able to trip
+//! over (rule ids match markdownlint so the messages can be looked up) plus a
+//! check that every `#fragment` link points at a heading or HTML anchor.
+
+use std::collections::HashSet;
+use std::fmt;
+
+pub struct Issue {
+    pub line: usize,
+    pub rule: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {}", self.line, self.rule, self.message)
+    }
+}
+
+pub struct Heading {
+    pub line: usize,
+    pub level: usize,
+    pub text: String,
+}
+
+pub struct Link {
+    pub line: usize,
+    pub target: String,
+    pub is_image: bool,
+}
+
+/// GitHub's heading anchor algorithm: lowercase, drop everything that is not
+/// a letter, digit, mark, space, `-` or `_`, then turn spaces into hyphens.
+/// Marks include the variation selector in emoji such as `🛠️`.
+pub fn slugify(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || is_mark(c) || c == ' ' || c == '-' || c == '_')
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Combining marks and variation selectors, the marks that turn up in
+/// headings; `char` has no general category lookup.
+fn is_mark(c: char) -> bool {
+    matches!(c,
+        '\u{300}'..='\u{36f}'
+        | '\u{1ab0}'..='\u{1aff}'
+        | '\u{1dc0}'..='\u{1dff}'
+        | '\u{20d0}'..='\u{20ff}'
+        | '\u{fe00}'..='\u{fe0f}'
+        | '\u{fe20}'..='\u{fe2f}'
+        | '\u{e0100}'..='\u{e01ef}')
+}
+
+/// Anchors GitHub generates for `headings`, including the `-1`, `-2`
+/// suffixes it adds to repeated titles.
+pub fn anchors(headings: &[Heading]) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    for heading in headings {
+        let base = slugify(&heading.text);
+        let mut slug = base.clone();
+        let mut n = 0;
+        while !seen.insert(slug.clone()) {
+            n += 1;
+            slug = format!("{base}-{n}");
+        }
+    }
+    seen
+}
+
+fn fence_marker(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+        Some(&trimmed[..3])
+    } else {
+        None
+    }
+}
+
+fn atx_heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') && !rest.starts_with('\t') {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn setext_level(line: &str) -> Option<usize> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        None
+    } else if trimmed.chars().all(|c| c == '=') {
+        Some(1)
+    } else if trimmed.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Whether `line` can be the text of a setext heading, i.e. it is plain
+/// paragraph text rather than a list item, quote, HTML or another block.
+fn is_paragraph_text(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !(trimmed.is_empty()
+        || atx_heading(line).is_some()
+        || setext_level(line).is_some()
+        || trimmed.starts_with("- ")
+        || trimmed.starts_with("* ")
+        || trimmed.starts_with("+ ")
+        || trimmed.starts_with('>')
+        || trimmed.starts_with('<')
+        || trimmed.starts_with('|')
+        || trimmed
+            .split_once(". ")
+            .is_some_and(|(n, _)| n.chars().all(|c| c.is_ascii_digit())))
+}
+
+/// Lines of `markdown` (1-based) that are outside fenced code blocks.
+fn prose_lines(markdown: &str) -> Vec<(usize, &str)> {
+    let mut fence: Option<&str> = None;
+    let mut lines = Vec::new();
+    for (i, line) in markdown.lines().enumerate() {
+        match (fence, fence_marker(line)) {
+            (None, Some(marker)) => fence = Some(marker),
+            (Some(open), Some(marker)) if open == marker => fence = None,
+            (None, None) => lines.push((i + 1, line)),
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// All ATX and setext headings outside code blocks.
+pub fn headings(markdown: &str) -> Vec<Heading> {
+    let lines = prose_lines(markdown);
+    let mut headings = Vec::new();
+    for (idx, &(line_no, line)) in lines.iter().enumerate() {
+        if let Some((level, text)) = atx_heading(line) {
+            headings.push(Heading {
+                line: line_no,
+                level,
+                text: text.to_string(),
+            });
+        } else if let (Some(level), Some(&(prev_no, prev))) =
+            (setext_level(line), idx.checked_sub(1).map(|p| &lines[p]))
+        {
+            if prev_no + 1 == line_no && is_paragraph_text(prev) {
+                headings.push(Heading {
+                    line: prev_no,
+                    level,
+                    text: prev.trim().to_string(),
+                });
+            }
+        }
+    }
+    headings
+}
+
+fn strip_code_spans(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_code = false;
+    for c in line.chars() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if !in_code {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn link_target(raw: &str) -> String {
+    let raw = raw.trim();
+    if let Some(rest) = raw.strip_prefix('<') {
+        return rest.split('>').next().unwrap_or_default().to_string();
+    }
+    raw.split_whitespace().next().unwrap_or_default().to_string()
+}
+
+/// Every Markdown link, image and HTML `href`/`src` outside code.
+pub fn links(markdown: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    for (line_no, line) in prose_lines(markdown) {
+        let line = strip_code_spans(line);
+
+        let mut rest = line.as_str();
+        while let Some(pos) = rest.find("](") {
+            let is_image = rest[..pos]
+                .rfind('[')
+                .is_some_and(|open| rest[..open].ends_with('!'));
+            let after = &rest[pos + 2..];
+            let mut depth = 0;
+            let end = after
+                .char_indices()
+                .find(|&(_, c)| match c {
+                    '(' => {
+                        depth += 1;
+                        false
+                    }
+                    ')' if depth == 0 => true,
+                    ')' => {
+                        depth -= 1;
+                        false
+                    }
+                    _ => false,
+                })
+                .map_or(after.len(), |(i, _)| i);
+            links.push(Link {
+                line: line_no,
+                target: link_target(&after[..end]),
+                is_image,
+            });
+            rest = &after[end..];
+        }
+
+        for (attr, is_image) in [("href=\"", false), ("src=\"", true)] {
+            let mut rest = line.as_str();
+            while let Some(pos) = rest.find(attr) {
+                let after = &rest[pos + attr.len()..];
+                let end = after.find('"').unwrap_or(after.len());
+                links.push(Link {
+                    line: line_no,
+                    target: after[..end].to_string(),
+                    is_image,
+                });
+                rest = &after[end..];
+            }
+        }
+    }
+    links
+}
+
+/// Every fragment a link into `markdown` may use: heading anchors plus
+/// HTML `id`/`name` attributes.
+pub fn document_anchors(markdown: &str) -> HashSet<String> {
+    let mut valid = anchors(&headings(markdown));
+    valid.extend(html_anchors(markdown));
+    valid
+}
+
+/// `id="…"` and `name="…"` attributes, which are valid fragment targets too.
+fn html_anchors(markdown: &str) -> Vec<String> {
+    let mut anchors = Vec::new();
+    for (_, line) in prose_lines(markdown) {
+        for attr in ["id=\"", "name=\""] {
+            let mut rest = line;
+            while let Some(pos) = rest.find(attr) {
+                let after = &rest[pos + attr.len()..];
+                let end = after.find('"').unwrap_or(after.len());
+                anchors.push(after[..end].to_string());
+                rest = &after[end..];
+            }
+        }
+    }
+    anchors
+}
+
+pub fn lint(markdown: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    // Whitespace rules apply everywhere, code blocks included.
+    for (i, line) in lines.iter().enumerate() {
+        let trailing = line.len() - line.trim_end_matches([' ', '\t']).len();
+        if trailing > 0 && !(trailing == 2 && line.ends_with("  ") && !line.trim().is_empty()) {
+            issues.push(Issue {
+                line: i + 1,
+                rule: "MD009/no-trailing-spaces",
+                message: format!("{trailing} trailing whitespace character(s)"),
+            });
+        }
+        if line.contains('\t') {
+            issues.push(Issue {
+                line: i + 1,
+                rule: "MD010/no-hard-tabs",
+                message: String::from("hard tab character"),
+            });
+        }
+    }
+
+    // Code fences.
+    let mut open: Option<(usize, &str, bool)> = None;
+    for (i, line) in lines.iter().enumerate() {
+        match (open, fence_marker(line)) {
+            (None, Some(marker)) => {
+                let info = line.trim_start()[3..].trim_start_matches(['`', '~']).trim();
+                if info.is_empty() {
+                    issues.push(Issue {
+                        line: i + 1,
+                        rule: "MD040/fenced-code-language",
+                        message: String::from("fenced code block has no language"),
+                    });
+                }
+                open = Some((i + 1, marker, false));
+            }
+            (Some((start, marker, has_content)), Some(closing)) if marker == closing => {
+                if !has_content {
+                    issues.push(Issue {
+                        line: start,
+                        rule: "no-empty-code-blocks",
+                        message: String::from("fenced code block is empty"),
+                    });
+                }
+                open = None;
+            }
+            (Some((start, marker, has_content)), _) => {
+                open = Some((start, marker, has_content || !line.trim().is_empty()));
+            }
+            (None, None) => {}
+        }
+    }
+    if let Some((start, _, _)) = open {
+        issues.push(Issue {
+            line: start,
+            rule: "MD046/code-block-style",
+            message: String::from("fenced code block is never closed"),
+        });
+    }
+
+    // Blank lines outside code.
+    let prose = prose_lines(markdown);
+    for pair in prose.windows(2) {
+        let ((a_no, a), (b_no, b)) = (pair[0], pair[1]);
+        if b_no == a_no + 1 && a.trim().is_empty() && b.trim().is_empty() {
+            issues.push(Issue {
+                line: b_no,
+                rule: "MD012/no-multiple-blanks",
+                message: String::from("multiple consecutive blank lines"),
+            });
+        }
+    }
+
+    // Headings.
+    let headings = headings(markdown);
+    let mut previous_level = 0;
+    let mut seen_h1 = false;
+    for heading in &headings {
+        if previous_level > 0 && heading.level > previous_level + 1 {
+            issues.push(Issue {
+                line: heading.line,
+                rule: "MD001/heading-increment",
+                message: format!(
+                    "heading jumps from level {} to {}",
+                    previous_level, heading.level
+                ),
+            });
+        }
+        previous_level = heading.level;
+
+        if heading.level == 1 {
+            if seen_h1 {
+                issues.push(Issue {
+                    line: heading.line,
+                    rule: "MD025/single-h1",
+                    message: String::from("more than one top-level heading"),
+                });
+            }
+            seen_h1 = true;
+        }
+
+        if heading.text.is_empty() {
+            issues.push(Issue {
+                line: heading.line,
+                rule: "no-empty-headings",
+                message: String::from("heading has no text"),
+            });
+        }
+
+        let before = heading.line.checked_sub(2).and_then(|i| lines.get(i));
+        let after_idx = if atx_heading(lines[heading.line - 1]).is_some() {
+            heading.line
+        } else {
+            heading.line + 1
+        };
+        let after = lines.get(after_idx);
+        if before.is_some_and(|l| !l.trim().is_empty())
+            || after.is_some_and(|l| !l.trim().is_empty())
+        {
+            issues.push(Issue {
+                line: heading.line,
+                rule: "MD022/blanks-around-headings",
+                message: String::from("heading should be surrounded by blank lines"),
+            });
+        }
+    }
+
+    // Links.
+    let valid = document_anchors(markdown);
+    for link in links(markdown) {
+        if link.target.is_empty() || link.target == "#" {
+            issues.push(Issue {
+                line: link.line,
+                rule: "MD042/no-empty-links",
+                message: String::from("link has no destination"),
+            });
+        } else if let Some(fragment) = link.target.strip_prefix('#') {
+            if !valid.contains(fragment) && !valid.contains(&fragment.to_lowercase()) {
+                issues.push(Issue {
+                    line: link.line,
+                    rule: "MD051/link-fragments",
+                    message: format!("no heading or anchor matches #{fragment}"),
+                });
+            }
+        }
+    }
+
+    if !markdown.is_empty() && (!markdown.ends_with('\n') || markdown.ends_with("\n\n")) {
+        issues.push(Issue {
+            line: lines.len(),
+            rule: "MD047/single-trailing-newline",
+            message: String::from("file should end with a single newline"),
+        });
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(markdown: &str) -> Vec<(usize, &'static str)> {
+        lint(markdown).into_iter().map(|issue| (issue.line, issue.rule)).collect()
+    }
+
+    #[test]
+    fn clean_document() {
+        assert_eq!(rules("# Title\n\nText.\n\n## Part\n\n- item\n"), []);
+    }
+
+    #[test]
+    fn trailing_spaces() {
+        assert_eq!(rules("# T\n\ntext \n"), [(3, "MD009/no-trailing-spaces")]);
+        // Two spaces are a hard line break
+        assert_eq!(rules("# T\n\ntext  \nmore\n"), []);
+        assert_eq!(rules("# T\n\ntext\n  \nmore\n"), [(4, "MD009/no-trailing-spaces")]);
+    }
+
+    #[test]
+    fn hard_tabs() {
+        assert_eq!(rules("# T\n\n\tindented\n"), [(3, "MD010/no-hard-tabs")]);
+    }
+
+    #[test]
+    fn code_fences() {
+        assert_eq!(rules("```\ncode\n```\n"), [(1, "MD040/fenced-code-language")]);
+        assert_eq!(rules("~~~\ncode\n~~~\n"), [(1, "MD040/fenced-code-language")]);
+        assert_eq!(rules("```rust\n```\n"), [(1, "no-empty-code-blocks")]);
+        assert_eq!(rules("```rust\n\n```\n"), [(1, "no-empty-code-blocks")]);
+        assert_eq!(rules("# T\n\n```rust\ncode\n"), [(3, "MD046/code-block-style")]);
+    }
+
+    #[test]
+    fn fenced_code_is_not_prose() {
+        let markdown = "# T\n\n```md\n# Not a heading\n\n\n[x](#nowhere)\nNot setext\n===\n```\n";
+        assert_eq!(rules(markdown), []);
+        assert_eq!(headings(markdown).len(), 1);
+        assert!(links(markdown).is_empty());
+        // A fence only closes with the marker that opened it
+        assert_eq!(rules("# T\n\n~~~text\n```\n# Inside\n~~~\n"), []);
+        assert_eq!(headings("~~~text\n```\n# Inside\n~~~\n").len(), 0);
+    }
+
+    #[test]
+    fn multiple_blanks() {
+        assert_eq!(rules("# T\n\na\n\n\nb\n"), [(5, "MD012/no-multiple-blanks")]);
+    }
+
+    #[test]
+    fn heading_increment() {
+        assert_eq!(rules("# T\n\n### Deep\n"), [(3, "MD001/heading-increment")]);
+        assert_eq!(rules("# T\n\n## A\n\n### B\n\n## C\n"), []);
+    }
+
+    #[test]
+    fn single_h1() {
+        assert_eq!(rules("# A\n\n# B\n"), [(3, "MD025/single-h1")]);
+    }
+
+    #[test]
+    fn empty_heading() {
+        assert_eq!(rules("# T\n\n##\n"), [(3, "no-empty-headings")]);
+        // `#hashtag` is not a heading at all
+        assert_eq!(rules("# T\n\n#hashtag\n"), []);
+    }
+
+    #[test]
+    fn blanks_around_headings() {
+        assert_eq!(rules("# T\ntext\n"), [(1, "MD022/blanks-around-headings")]);
+        assert_eq!(rules("# T\n\ntext\n## Next\n\nmore\n"), [(4, "MD022/blanks-around-headings")]);
+        assert_eq!(rules("Title\n=====\ntext\n"), [(1, "MD022/blanks-around-headings")]);
+        assert_eq!(rules("Title\n=====\n\ntext\n"), []);
+    }
+
+    #[test]
+    fn setext_headings() {
+        let found = headings("Title\n=====\n\nPart\n----\n\nText\n");
+        let found: Vec<_> = found.iter().map(|h| (h.line, h.level, h.text.as_str())).collect();
+        assert_eq!(found, [(1, 1, "Title"), (4, 2, "Part")]);
+        // Underlines after a list item, a blank line or HTML are not headings
+        assert!(headings("- item\n---\n").is_empty());
+        assert!(headings("text\n\n---\n").is_empty());
+        assert!(headings("<div>\n---\n").is_empty());
+        assert_eq!(rules("Title\n=====\n\nOther\n=====\n"), [(4, "MD025/single-h1")]);
+    }
+
+    #[test]
+    fn empty_links() {
+        assert_eq!(rules("# T\n\n[a]()\n"), [(3, "MD042/no-empty-links")]);
+        assert_eq!(rules("# T\n\n[a](#)\n"), [(3, "MD042/no-empty-links")]);
+    }
+
+    #[test]
+    fn link_fragments() {
+        assert_eq!(rules("# T\n\n## Built With\n\n[a](#built-with)\n"), []);
+        assert_eq!(rules("# T\n\n[a](#missing)\n"), [(3, "MD051/link-fragments")]);
+        assert_eq!(rules("# T\n\n<a name=\"here\"></a>\n\n[a](#here)\n"), []);
+        assert_eq!(rules("# T\n\n<p id=\"top\">x</p>\n\n<a href=\"#top\">up</a>\n"), []);
+        // Links in code spans are not links
+        assert_eq!(rules("# T\n\n`[a](#missing)`\n"), []);
+    }
+
+    #[test]
+    fn duplicate_headings_get_suffixes() {
+        let markdown = "# T\n\n## Intro\n\n## Intro\n\n## Intro\n\n[a](#intro) [b](#intro-1) [c](#intro-2)\n";
+        assert_eq!(rules(markdown), []);
+        assert_eq!(
+            rules("# T\n\n## Intro\n\n## Intro\n\n[a](#intro-2)\n"),
+            [(7, "MD051/link-fragments")]
+        );
+        // A heading that looks like a suffixed one takes the next free slug
+        let found = anchors(&headings("# A\n\n## A-1\n\n## A\n"));
+        let mut found: Vec<_> = found.into_iter().collect();
+        found.sort();
+        assert_eq!(found, ["a", "a-1", "a-2"]);
+    }
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slugify("Built With"), "built-with");
+        assert_eq!(slugify("📋 Table of Contents"), "-table-of-contents");
+        // GitHub keeps the variation selector of `🛠️`
+        assert_eq!(slugify("🛠️ Built With"), "\u{fe0f}-built-with");
+        assert_eq!(slugify("❤️ Thanks"), "\u{fe0f}-thanks");
+        assert_eq!(slugify("Cafe\u{301}"), "cafe\u{301}");
+        assert_eq!(slugify("C++ & Rust"), "c--rust");
+        assert_eq!(slugify("snake_case-name"), "snake_case-name");
+    }
+
+    #[test]
+    fn single_trailing_newline() {
+        assert_eq!(rules("# T"), [(1, "MD047/single-trailing-newline")]);
+        assert_eq!(rules("# T\n\n"), [(2, "MD047/single-trailing-newline")]);
+        assert_eq!(rules(""), []);
+    }
+}
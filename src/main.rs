@@ -1,647 +1,2180 @@
+mod cli;
+mod error;
+mod fuzzy;
+mod history;
+mod keymap;
+mod session;
+mod terminal;
+mod theme;
+
+use std::borrow::Cow;
+use std::env;
 use std::fs;
-use std::io;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::{self, Event, KeyCode, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
     Frame, Terminal,
 };
 
+use readme_generator::changelog;
+use readme_generator::community::CommunityFile;
+use readme_generator::config::Config;
+use readme_generator::field::{self, Field};
+use readme_generator::i18n::{self, Lang};
+use readme_generator::license::{self, Expression, License};
+use readme_generator::output::{self, WriteReport};
+use readme_generator::preset::{self, Preset};
+use readme_generator::render::{self, Badge, HeadingStyle, HeadingSyntax, Section};
+use readme_generator::{diff, links, lint, messages};
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use cli::Args;
+use error::Error;
+use history::{Edit, History};
+use keymap::{Action, Context, Key, Keymap, Resolved};
+use session::Session;
+use theme::Theme;
+
 #[derive(PartialEq)]
 enum InputMode {
     Navigation,
     Editing,
+    EditingPath,
+    ConfirmOverwrite,
+    /// Searching the command palette; the query is in `input`.
+    Palette,
+}
+
+/// One step of the wizard.
+#[derive(Clone, Copy, PartialEq)]
+enum Page {
+    Preset,
+    License,
+    /// The fields of a section, or the header fields for `None`.
+    Fields(Option<Section>),
+    Review,
+}
+
+/// Something on screen that reacts to a click.
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Page(usize),
+    Field(usize),
+    Preset(usize),
+    License(usize),
+    /// A match in the command palette, by rank.
+    Palette(usize),
+}
+
+/// Something the command palette can jump to or do.
+#[derive(Clone, Copy, PartialEq)]
+enum Entry {
+    Page(usize),
+    Field(usize),
+    Command(Command),
 }
 
-struct Field {
-    name: String,
-    value: String,
-    description: String,
+/// Palette commands for things that otherwise take a trip to a page.
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+    Generate,
+    TogglePreview,
+    ChangeOutput,
+    ChangeLicense,
+    ChangePreset,
+    Undo,
+    Redo,
+    Quit,
 }
 
+impl Command {
+    const ALL: [Command; 8] = [
+        Command::Generate,
+        Command::TogglePreview,
+        Command::ChangeOutput,
+        Command::ChangeLicense,
+        Command::ChangePreset,
+        Command::Undo,
+        Command::Redo,
+        Command::Quit,
+    ];
+
+    /// Message key of the command's name.
+    fn key(self) -> &'static str {
+        match self {
+            Command::Generate => "command.generate",
+            Command::TogglePreview => "command.toggle-preview",
+            Command::ChangeOutput => "command.change-output",
+            Command::ChangeLicense => "command.change-license",
+            Command::ChangePreset => "command.change-preset",
+            Command::Undo => "command.undo",
+            Command::Redo => "command.redo",
+            Command::Quit => "command.quit",
+        }
+    }
+}
+
+/// Where the last frame put its clickable parts.
+#[derive(Default)]
+struct Regions {
+    targets: Vec<(Rect, Target)>,
+    /// The scrollable output on the review page.
+    scroll: Option<Rect>,
+    /// The checks panel on the review page and how far it can scroll.
+    checks: Option<(Rect, u16)>,
+}
+
+impl Regions {
+    /// Registers the rows of a bordered list drawn in `area`, one entry
+    /// per item with its height in rows.
+    fn list(&mut self, area: Rect, items: impl IntoIterator<Item = (u16, Target)>) {
+        let inner = Block::default().borders(Borders::ALL).inner(area);
+        let mut y = inner.y;
+        for (height, target) in items {
+            if y >= inner.bottom() {
+                break;
+            }
+            let height = height.min(inner.bottom() - y);
+            self.targets.push((Rect { y, height, ..inner }, target));
+            y += height;
+        }
+    }
+
+    fn target(&self, column: u16, row: u16) -> Option<Target> {
+        self.targets
+            .iter()
+            .find(|(area, _)| contains(*area, column, row))
+            .map(|(_, target)| *target)
+    }
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+/// Clicks on the same target closer together than this are a double click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Below this width the terminal loses its outer margin and two-pane pages
+/// stack their panes.
+const NARROW_WIDTH: u16 = 80;
+/// Below this height the outer margin goes too.
+const SHORT_HEIGHT: u16 = 30;
+/// Smallest terminal the wizard is drawn in; anything smaller gets a
+/// message asking for more room.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 16;
+
 struct App {
     input: String,
     input_mode: InputMode,
+    /// Index into [`App::pages`].
+    page: usize,
     fields: Vec<Field>,
+    /// Fields of sections that are not rendered, kept so their values
+    /// survive switching presets.
+    hidden_fields: Vec<Field>,
     current_field: usize,
+    /// Committed field edits, for undo and redo.
+    history: History,
+    preset: Option<&'static Preset>,
+    selected_preset: usize,
+    /// Sections and badges set by the config, which a preset must not
+    /// replace.
+    sections_pinned: bool,
+    badges_pinned: bool,
     license_options: Vec<String>,
     selected_license: usize,
+    output_path: PathBuf,
+    to_stdout: bool,
+    force: bool,
+    error: Option<String>,
+    /// Whether the review page shows the diff against the existing files
+    /// rather than the full output.
+    show_diff: bool,
+    review_scroll: u16,
+    checks_scroll: u16,
+    render_options: render::Options,
+    /// Languages to write, the first one to `output_path` and the others to
+    /// `README.<code>.md` next to it.
+    languages: Vec<Lang>,
+    ui_lang: Lang,
+    keymap: Keymap,
+    theme: Theme,
+    regions: Regions,
+    last_click: Option<(Instant, Target)>,
+    /// Rank of the highlighted palette match.
+    palette_selected: usize,
+    /// Releases for CHANGELOG.md, read from the git history of the project.
+    changelog: Vec<changelog::Release>,
+    /// Keep the Changelog section whatever the preset's sections are.
+    with_changelog: bool,
+    /// License files already in the project directory, by file name.
+    license_files: Vec<(String, &'static License)>,
+    /// The license the project's Cargo.toml declares.
+    cargo_license: Option<String>,
+    /// Whether the license was chosen by the config, the command line or
+    /// the user on the license page, which the files on disk must not
+    /// override. Only a chosen license is saved with the session.
+    license_pinned: bool,
+}
+
+/// The licenses offered: the whole catalog, with the dual license common
+/// for Rust crates right after its two halves.
+fn license_options() -> Vec<String> {
+    let mut options: Vec<String> = license::LICENSES
+        .iter()
+        .map(|license| license.id.to_string())
+        .collect();
+    options.insert(2, String::from("MIT OR Apache-2.0"));
+    options
 }
 
 impl Default for App {
     fn default() -> App {
+        App::new(Lang::En)
+    }
+}
+
+impl App {
+    fn new(ui_lang: Lang) -> App {
+        let (fields, hidden_fields) =
+            field::partition(field::default_fields(ui_lang), &Section::DEFAULT, &[]);
         App {
             input: String::new(),
             input_mode: InputMode::Navigation,
-            fields: vec![
-                Field {
-                    name: String::from("Repository Name"),
-                    value: String::new(),
-                    description: String::from("The name of your project/repository (e.g., username/repo)"),
-                },
-                Field {
-                    name: String::from("Project Title"),
-                    value: String::new(),
-                    description: String::from("A catchy title for your project"),
-                },
-                Field {
-                    name: String::from("Short Description"),
-                    value: String::new(),
-                    description: String::from("A brief one-line description of your project"),
-                },
-                Field {
-                    name: String::from("Detailed Description"),
-                    value: String::new(),
-                    description: String::from("A detailed explanation of what your project does and why it's useful"),
-                },
-                Field {
-                    name: String::from("Features"),
-                    value: String::new(),
-                    description: String::from("Key features of your project (separate with semicolons)"),
-                },
-                Field {
-                    name: String::from("Technologies"),
-                    value: String::new(),
-                    description: String::from("Technologies used (separate with semicolons) e.g., React;TypeScript;Node.js"),
-                },
-                Field {
-                    name: String::from("Prerequisites"),
-                    value: String::new(),
-                    description: String::from("Required software/tools to run your project (separate with semicolons)"),
-                },
-                Field {
-                    name: String::from("Installation"),
-                    value: String::new(),
-                    description: String::from("Step-by-step installation instructions (separate steps with semicolons)"),
-                },
-                Field {
-                    name: String::from("Usage Example"),
-                    value: String::new(),
-                    description: String::from("Example code or commands to use your project"),
-                },
-                Field {
-                    name: String::from("API Documentation"),
-                    value: String::new(),
-                    description: String::from("Brief API documentation or endpoints (optional)"),
-                },
-                Field {
-                    name: String::from("Contributing Guidelines"),
-                    value: String::new(),
-                    description: String::from("How others can contribute to your project"),
-                },
-                Field {
-                    name: String::from("Tests"),
-                    value: String::new(),
-                    description: String::from("How to run tests (separate steps with semicolons)"),
-                },
-                Field {
-                    name: String::from("Authors"),
-                    value: String::new(),
-                    description: String::from("Project authors/maintainers (separate with semicolons)"),
-                },
-            ],
+            page: 0,
+            fields,
+            hidden_fields,
             current_field: 0,
-            license_options: vec![
-                String::from("MIT License"),
-                String::from("Apache License 2.0"),
-                String::from("GNU GPL v3"),
-                String::from("BSD 3-Clause"),
-                String::from("ISC License"),
-            ],
+            history: History::default(),
+            preset: None,
+            selected_preset: 0,
+            sections_pinned: false,
+            badges_pinned: false,
+            license_options: license_options(),
             selected_license: 0,
+            output_path: PathBuf::from(output::DEFAULT_FILE_NAME),
+            to_stdout: false,
+            force: false,
+            error: None,
+            show_diff: true,
+            review_scroll: 0,
+            checks_scroll: 0,
+            render_options: render::Options::default(),
+            languages: vec![Lang::En],
+            ui_lang,
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            regions: Regions::default(),
+            last_click: None,
+            palette_selected: 0,
+            changelog: Vec::new(),
+            with_changelog: false,
+            license_files: Vec::new(),
+            cargo_license: None,
+            license_pinned: false,
         }
     }
-}
 
-impl App {
-    fn all_fields_filled(&self) -> bool {
-        self.fields.iter().all(|field| !field.value.is_empty())
+    fn text(&self, key: &str) -> &'static str {
+        messages::text(self.ui_lang, key)
     }
 
-    fn generate_preview(&self) -> String {
-        let repo_name = &self.fields[0].value;
-        let project_title = &self.fields[1].value;
-        let short_desc = &self.fields[2].value;
-        let detailed_desc = &self.fields[3].value;
-        let features = self.fields[4].value.split(';').collect::<Vec<_>>();
-        let technologies = self.fields[5].value.split(';').collect::<Vec<_>>();
-        let prerequisites = self.fields[6].value.split(';').collect::<Vec<_>>();
-        let installation = self.fields[7].value.split(';').collect::<Vec<_>>();
-        let usage = &self.fields[8].value;
-        let api_docs = &self.fields[9].value;
-        let contributing = &self.fields[10].value;
-        let tests = self.fields[11].value.split(';').collect::<Vec<_>>();
-        let authors = self.fields[12].value.split(';').collect::<Vec<_>>();
-        let license = &self.license_options[self.selected_license];
-
-        let mut badges = Vec::new();
-        if !repo_name.is_empty() {
-            badges.push(format!("[![Stars](https://img.shields.io/github/stars/{repo_name}?style=flat-square)](https://github.com/{repo_name}/stargazers)"));
-            badges.push(format!("[![Forks](https://img.shields.io/github/forks/{repo_name}?style=flat-square)](https://github.com/{repo_name}/network/members)"));
-            badges.push(format!("[![Issues](https://img.shields.io/github/issues/{repo_name}?style=flat-square)](https://github.com/{repo_name}/issues)"));
-            badges.push(format!("[![License](https://img.shields.io/github/license/{repo_name}?style=flat-square)](https://github.com/{repo_name}/blob/main/LICENSE)"));
-        }
-
-        let tech_badges: Vec<String> = technologies.iter()
-            .filter(|&t| !t.is_empty())
-            .map(|tech| {
-                let tech = tech.trim().to_lowercase();
-                format!("![{}](https://img.shields.io/badge/-{}-informational?style=flat-square&logo={}&logoColor=white)",
-                    tech, tech, tech)
+    /// Which key bindings apply in the current input mode.
+    fn context(&self) -> Context {
+        match self.input_mode {
+            InputMode::Navigation => Context::Navigation,
+            InputMode::Editing | InputMode::EditingPath => Context::Text,
+            InputMode::ConfirmOverwrite => Context::Confirm,
+            InputMode::Palette => Context::Palette,
+        }
+    }
+
+    /// A bordered box in the theme's colors.
+    fn block<'a>(&self, title: impl Into<Spans<'a>>) -> Block<'a> {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.border)
+            .title(title)
+    }
+
+    fn field_mut(&mut self, id: &str) -> Option<&mut Field> {
+        self.fields
+            .iter_mut()
+            .chain(self.hidden_fields.iter_mut())
+            .find(|field| field.id == id)
+    }
+
+    /// Shows exactly the fields the selected sections need.
+    fn sync_fields(&mut self) {
+        let mut all = std::mem::take(&mut self.fields);
+        all.append(&mut self.hidden_fields);
+        (self.fields, self.hidden_fields) =
+            field::partition(all, &self.render_options.sections, &self.render_options.community);
+        self.current_field = self.current_field.min(self.fields.len() - 1);
+    }
+
+    /// Switches to `preset`: its sections and badges (unless the config
+    /// set them), Usage code block language and field prompts.
+    fn apply_preset(&mut self, preset: &'static Preset) {
+        self.preset = Some(preset);
+        if !self.sections_pinned {
+            self.render_options.sections = preset.sections.to_vec();
+            if self.with_changelog {
+                self.add_changelog_section();
+            }
+        }
+        if !self.badges_pinned {
+            self.render_options.badges = preset.badges.to_vec();
+        }
+        self.render_options.usage_lang = preset.usage_lang.to_string();
+        let lang = self.ui_lang;
+        for field in self.fields.iter_mut().chain(self.hidden_fields.iter_mut()) {
+            let prompt = format!("preset.{}.prompt.{}", preset.id, field.id);
+            field.description = messages::lookup(lang, &prompt)
+                .unwrap_or_else(|| messages::text(lang, &format!("field.{}.description", field.id)))
+                .to_string();
+        }
+        self.sync_fields();
+    }
+
+    /// The selected preset's example value for `field`.
+    fn example(&self, field: &Field) -> Option<String> {
+        let repo = self.fields.iter().find(|field| field.id == "repository")?;
+        self.preset?.example(field.id, &repo.value)
+    }
+
+    /// Stores the input buffer in the current field, recording the change.
+    fn commit_input(&mut self) {
+        let value: String = self.input.drain(..).collect();
+        let field = &mut self.fields[self.current_field];
+        let before = std::mem::replace(&mut field.value, value);
+        self.history.record(Edit {
+            field: field.id.to_string(),
+            before,
+            after: field.value.clone(),
+        });
+    }
+
+    fn undo(&mut self) {
+        if let Some(edit) = self.history.undo() {
+            self.restore_value(&edit.field, edit.before);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(edit) = self.history.redo() {
+            self.restore_value(&edit.field, edit.after);
+        }
+    }
+
+    /// Sets field `id` to `value` from the history. While editing, the
+    /// input follows if it is the same field; otherwise the wizard moves to
+    /// the field's page.
+    fn restore_value(&mut self, id: &str, value: String) {
+        let Some(field) = self.field_mut(id) else {
+            return;
+        };
+        field.value = value;
+        let Some(index) = self.fields.iter().position(|field| field.id == id) else {
+            return;
+        };
+        if self.input_mode == InputMode::Editing {
+            if index == self.current_field {
+                self.input = self.fields[index].value.clone();
+            }
+            return;
+        }
+        let pages = self.pages();
+        if let Some(page) = pages.iter().position(|&page| self.page_fields(page).contains(&index)) {
+            self.go_to(page);
+            self.current_field = index;
+        }
+    }
+
+    /// Everything needed to pick up where this run stopped.
+    fn session(&self, dir: &Path) -> Session {
+        Session {
+            dir: dir.to_path_buf(),
+            preset: self.preset.map(|preset| preset.id.to_string()),
+            // Only a license the user chose; otherwise the next run detects it again
+            license: self.license_pinned.then(|| self.license().to_string()),
+            page: self.page,
+            fields: self
+                .fields
+                .iter()
+                .chain(&self.hidden_fields)
+                .filter(|field| !field.value.is_empty())
+                .map(|field| (field.id.to_string(), field.value.clone()))
+                .collect(),
+            history: self.history.clone(),
+        }
+    }
+
+    fn resume(&mut self, session: Session) {
+        if let Some(preset) = session.preset.as_deref().and_then(Preset::from_id) {
+            self.selected_preset = preset::PRESETS.iter().position(|p| p.id == preset.id).unwrap_or(0);
+            self.apply_preset(preset);
+        }
+        if let Some(license) = session.license {
+            self.license_pinned |= self.select_license(&license).is_ok();
+        }
+        for (id, value) in session.fields {
+            if let Some(field) = self.field_mut(&id) {
+                field.value = value;
+            }
+        }
+        self.history = session.history;
+        self.go_to(session.page);
+    }
+
+    /// The wizard steps: preset, license, one page per section with
+    /// fields, then review.
+    fn pages(&self) -> Vec<Page> {
+        let mut pages = vec![Page::Preset, Page::License, Page::Fields(None)];
+        pages.extend(
+            self.render_options
+                .sections
+                .iter()
+                .filter(|section| !section.fields().is_empty())
+                .map(|&section| Page::Fields(Some(section))),
+        );
+        // Community files ask for their fields on the Contributing page,
+        // even when the README has no such section
+        let contributing = Page::Fields(Some(Section::Contributing));
+        if !self.render_options.community.is_empty() && !pages.contains(&contributing) {
+            pages.push(contributing);
+        }
+        pages.push(Page::Review);
+        pages
+    }
+
+    fn current_page(&self) -> Page {
+        let pages = self.pages();
+        pages[self.page.min(pages.len() - 1)]
+    }
+
+    /// Indices into `fields` of the fields asked for on `page`.
+    fn page_fields(&self, page: Page) -> Vec<usize> {
+        let mut ids: Vec<&str> = match page {
+            Page::Fields(None) => field::HEADER_FIELDS.to_vec(),
+            Page::Fields(Some(section)) => section.fields().to_vec(),
+            _ => Vec::new(),
+        };
+        if page == Page::Fields(Some(Section::Contributing)) {
+            let sections = &self.render_options.sections;
+            for file in &self.render_options.community {
+                for &id in file.fields() {
+                    let elsewhere = sections.iter().any(|section| section.fields().contains(&id));
+                    if !elsewhere && !ids.contains(&id) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+        ids.iter()
+            .filter_map(|id| self.fields.iter().position(|field| field.id == *id))
+            .collect()
+    }
+
+    fn page_name(&self, page: Page) -> String {
+        match page {
+            Page::Preset => self.text("title.presets").to_string(),
+            Page::License => self.text("title.license").to_string(),
+            Page::Fields(None) => self.text("page.project").to_string(),
+            Page::Fields(Some(section)) => i18n::readme(self.ui_lang, section.id()).to_string(),
+            Page::Review => self.text("title.review").to_string(),
+        }
+    }
+
+    /// Whether `page` needs no more input: a preset was picked, the
+    /// license page was passed or every field on it is filled in.
+    fn page_done(&self, index: usize, page: Page) -> bool {
+        match page {
+            Page::Preset => self.preset.is_some(),
+            Page::License => self.page > index,
+            Page::Fields(_) => self
+                .page_fields(page)
+                .iter()
+                .all(|&i| !self.fields[i].value.is_empty()),
+            Page::Review => false,
+        }
+    }
+
+    fn go_to(&mut self, page: usize) {
+        self.page = page.min(self.pages().len() - 1);
+        if let Some(&first) = self.page_fields(self.current_page()).first() {
+            self.current_field = first;
+        }
+    }
+
+    /// Selects `field`, going to its page unless it is on the current one.
+    fn show_field(&mut self, field: usize) {
+        if !self.page_fields(self.current_page()).contains(&field) {
+            let pages = self.pages();
+            if let Some(page) = pages.iter().position(|&page| self.page_fields(page).contains(&field)) {
+                self.go_to(page);
+            }
+        }
+        self.current_field = field;
+    }
+
+    /// Palette entries matching the query in `input`, best first, each
+    /// with its label and a line of detail.
+    fn palette(&self) -> Vec<(Entry, String, String)> {
+        let pages = self.pages();
+        let mut entries: Vec<(Entry, String, String)> = Vec::new();
+        for (index, &page) in pages.iter().enumerate() {
+            if let Page::Fields(_) = page {
+                let name = self.page_name(page);
+                entries.push((Entry::Page(index), name, self.text("palette.page").to_string()));
+                for field in self.page_fields(page) {
+                    let value = &self.fields[field].value;
+                    let detail = if value.is_empty() {
+                        self.page_name(page)
+                    } else {
+                        format!("{} · {value}", self.page_name(page))
+                    };
+                    entries.push((Entry::Field(field), self.fields[field].name.clone(), detail));
+                }
+            }
+        }
+        for command in Command::ALL {
+            entries.push((
+                Entry::Command(command),
+                self.text(command.key()).to_string(),
+                self.text("palette.command").to_string(),
+            ));
+        }
+
+        // Names count double so a title match beats one in a value.
+        let mut ranked: Vec<(u32, (Entry, String, String))> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let name = fuzzy::score(&self.input, &entry.1).map(|score| score * 2);
+                let detail = fuzzy::score(&self.input, &entry.2);
+                Some((name.max(detail)?, entry))
             })
             .collect();
+        ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        ranked.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    fn empty_fields(&self) -> usize {
+        self.fields.iter().filter(|field| field.value.is_empty()).count()
+    }
 
-        let features_list = if features.is_empty() || features[0].is_empty() {
-            String::from("- <Features of your project>")
+    fn invalid_fields(&self) -> usize {
+        self.fields.iter().filter(|field| field.error().is_some()).count()
+    }
+
+    /// Every file a write produces, one per language.
+    fn targets(&self) -> Vec<(Lang, PathBuf)> {
+        self.languages
+            .iter()
+            .enumerate()
+            .map(|(i, &lang)| {
+                if i == 0 {
+                    (lang, self.output_path.clone())
+                } else {
+                    (lang, output::variant_path(&self.output_path, lang.code()))
+                }
+            })
+            .collect()
+    }
+
+    /// The community files with their contents, in the project directory
+    /// next to the README.
+    fn community_outputs(&self) -> Vec<(PathBuf, String)> {
+        let community = &self.render_options.community;
+        let dir = self.project_dir();
+        community
+            .iter()
+            .flat_map(|file| file.render(&self.fields, community))
+            .map(|(path, contents)| (dir.join(path), contents))
+            .collect()
+    }
+
+    /// Every file a write produces with its contents.
+    fn outputs(&self) -> Vec<(PathBuf, String)> {
+        let mut outputs: Vec<(PathBuf, String)> = self
+            .targets()
+            .into_iter()
+            .map(|(lang, path)| (path, self.render(lang)))
+            .collect();
+        outputs.extend(self.community_outputs());
+        outputs.extend(self.changelog_output());
+        outputs.extend(self.license_outputs());
+        outputs
+    }
+
+    /// CHANGELOG.md next to the README, when the README links to it.
+    fn changelog_output(&self) -> Option<(PathBuf, String)> {
+        if !self.render_options.sections.contains(&Section::Changelog) {
+            return None;
+        }
+        let repo = self.fields.iter().find(|field| field.id == "repository");
+        let contents = changelog::render(&self.changelog, repo.map_or("", |field| field.value.trim()));
+        Some((self.project_dir().join("CHANGELOG.md"), contents))
+    }
+
+    fn planned_paths(&self) -> Vec<PathBuf> {
+        let readmes = self.targets().into_iter().map(|(_, path)| path);
+        let others = self
+            .community_outputs()
+            .into_iter()
+            .chain(self.changelog_output())
+            .chain(self.license_outputs());
+        readmes.chain(others.map(|(path, _)| path)).collect()
+    }
+
+    fn existing_targets(&self) -> Vec<PathBuf> {
+        self.planned_paths()
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    /// Writes the rendered README in every language and the community
+    /// files, backing up whatever was there before.
+    fn write_readme(&self) -> io::Result<Vec<WriteReport>> {
+        self.outputs()
+            .iter()
+            .map(|(path, contents)| output::write_readme(path, contents))
+            .collect()
+    }
+
+    /// Generates the README if everything is filled in and valid: either
+    /// the outcome to finish with, or `None` with an error or the overwrite
+    /// confirmation to show.
+    fn submit(&mut self) -> Option<Outcome> {
+        let empty = self.empty_fields();
+        let invalid = self.invalid_fields();
+        if empty > 0 {
+            self.error = Some(self.text("error.empty-fields").replace("{n}", &empty.to_string()));
+        } else if invalid > 0 {
+            self.error = Some(self.text("error.invalid-fields").replace("{n}", &invalid.to_string()));
+        } else if self.to_stdout {
+            return Some(Outcome::Stdout);
+        } else if !self.existing_targets().is_empty() && !self.force {
+            self.input_mode = InputMode::ConfirmOverwrite;
         } else {
-            features.iter()
-                .map(|f| format!("- {}", f.trim()))
-                .collect::<Vec<_>>()
-                .join("\n")
+            match self.write_readme() {
+                Ok(reports) => return Some(Outcome::Written(reports)),
+                Err(err) => self.error = Some(err.to_string()),
+            }
+        }
+        None
+    }
+
+    /// Unified diff of every existing target against what would be written.
+    fn diff(&self) -> String {
+        let mut out = String::new();
+        for (path, contents) in self.outputs() {
+            let Ok(old) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let name = path.display().to_string();
+            out.push_str(&diff::unified(&old, &contents, &name, &name, 3));
+        }
+        out
+    }
+
+    fn set_community(&mut self, ids: &[String]) -> Result<(), String> {
+        let mut files = Vec::new();
+        for id in ids {
+            let file = CommunityFile::from_id(id.trim()).ok_or_else(|| {
+                let known: Vec<&str> = CommunityFile::ALL.iter().map(|f| f.id()).collect();
+                format!("unknown community file '{id}' (available: {})", known.join(", "))
+            })?;
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+        self.render_options.community = files;
+        self.sync_fields();
+        Ok(())
+    }
+
+    fn set_languages(&mut self, codes: &[String]) -> Result<(), String> {
+        let mut languages = Vec::new();
+        for code in codes {
+            let lang = Lang::from_code(code).ok_or_else(|| {
+                let known: Vec<&str> = Lang::ALL.iter().map(|l| l.code()).collect();
+                format!("unsupported language '{code}' (available: {})", known.join(", "))
+            })?;
+            if !languages.contains(&lang) {
+                languages.push(lang);
+            }
+        }
+        if !languages.is_empty() {
+            self.languages = languages;
+        }
+        Ok(())
+    }
+
+    /// Applies the preset `id` and starts the wizard past the preset page.
+    fn select_preset(&mut self, id: &str) -> Result<(), String> {
+        let preset = Preset::from_id(id).ok_or_else(|| {
+            let known: Vec<&str> = preset::PRESETS.iter().map(|p| p.id).collect();
+            format!("unknown preset '{id}' (available: {})", known.join(", "))
+        })?;
+        self.selected_preset = preset::PRESETS.iter().position(|p| p.id == preset.id).unwrap_or(0);
+        self.apply_preset(preset);
+        self.go_to(1);
+        Ok(())
+    }
+
+    /// The selected license as an SPDX expression.
+    fn license(&self) -> &str {
+        &self.license_options[self.selected_license]
+    }
+
+    /// Selects the license `text` is an SPDX id, name or expression for,
+    /// adding expressions that are not in the list yet.
+    fn select_license(&mut self, text: &str) -> Result<(), String> {
+        let expression = Expression::parse(text)?;
+        let known = self.license_options.iter().position(|option| {
+            Expression::parse(option).is_ok_and(|option| option.same_as(&expression))
+        });
+        self.selected_license = match known {
+            Some(index) => index,
+            None => {
+                self.license_options.push(expression.id());
+                self.license_options.len() - 1
+            }
         };
+        Ok(())
+    }
 
-        let prereq_list = if prerequisites.is_empty() || prerequisites[0].is_empty() {
-            String::from("- <Prerequisites>")
-        } else {
-            prerequisites.iter()
-                .map(|p| format!("- {}", p.trim()))
-                .collect::<Vec<_>>()
-                .join("\n")
+    /// Copyright holder for the license files: the first author without
+    /// their email, or else the project's contributors.
+    fn copyright_holder(&self) -> String {
+        let value = |id: &str| {
+            self.fields
+                .iter()
+                .chain(&self.hidden_fields)
+                .find(|field| field.id == id)
+                .map_or("", |field| field.value.trim())
         };
+        let author = value("authors").split(';').next().unwrap_or("");
+        let author = author.split('<').next().unwrap_or("").trim();
+        match (author, value("title")) {
+            ("", "") => String::from("the contributors"),
+            ("", title) => format!("the {title} contributors"),
+            (author, _) => author.to_string(),
+        }
+    }
 
-        let install_steps = if installation.is_empty() || installation[0].is_empty() {
-            String::from("1. <Installation steps>")
+    /// The license files with a bundled text that do not exist yet; an
+    /// existing one keeps its own copyright line.
+    fn license_outputs(&self) -> Vec<(PathBuf, String)> {
+        let Ok(expression) = Expression::parse(self.license()) else {
+            return Vec::new();
+        };
+        let dir = self.project_dir();
+        expression
+            .files(&self.copyright_holder(), license::current_year())
+            .into_iter()
+            .map(|(name, text)| (dir.join(name), text))
+            .filter(|(path, _)| !path.exists())
+            .collect()
+    }
+
+    fn set_output(&mut self, raw: &str) {
+        self.to_stdout = raw.trim() == "-";
+        if !self.to_stdout {
+            self.output_path = output::resolve_output_path(raw);
+            self.scan_project();
+        }
+    }
+
+    /// Reads what the project directory already has: the releases in its
+    /// git history, when there is a Changelog section, and its license
+    /// files and Cargo.toml license, which preselect the license unless one
+    /// was chosen. Outside of a git repository the changelog has no
+    /// releases.
+    fn scan_project(&mut self) {
+        let dir = self.project_dir().to_path_buf();
+        self.changelog = if self.render_options.sections.contains(&Section::Changelog) {
+            changelog::read(&dir).unwrap_or_default()
         } else {
-            installation.iter()
-                .enumerate()
-                .map(|(i, step)| format!("{}. {}", i + 1, step.trim()))
-                .collect::<Vec<_>>()
-                .join("\n")
+            Vec::new()
+        };
+        self.license_files = license::detect(&dir);
+        self.cargo_license = license::cargo_license(&dir);
+        if !self.license_pinned {
+            if let Some(found) = self.found_license() {
+                let _ = self.select_license(&found);
+            }
+        }
+    }
+
+    /// The project's license going by its files, or by Cargo.toml when
+    /// there are none. Files cannot tell `OR` from `AND`, nor `-only` from
+    /// `-or-later`, so Cargo.toml decides when it names the same licenses;
+    /// otherwise it is `OR`.
+    fn found_license(&self) -> Option<String> {
+        let cargo = self
+            .cargo_license
+            .as_deref()
+            .and_then(|text| Expression::parse(text).ok());
+        let mut ids: Vec<&str> = Vec::new();
+        for (_, license) in &self.license_files {
+            if !ids.contains(&license.id) {
+                ids.push(license.id);
+            }
+        }
+        if ids.is_empty() {
+            return cargo.map(|cargo| cargo.id());
+        }
+        let files = Expression::parse(&ids.join(" OR ")).ok()?;
+        match cargo {
+            Some(cargo) if cargo.same_families(&files) => Some(cargo.id()),
+            _ => Some(files.id()),
+        }
+    }
+
+    /// Where the selected license disagrees with the license files on disk
+    /// or with Cargo.toml, and a Cargo.toml license that cannot be read.
+    fn license_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let cargo = self
+            .cargo_license
+            .as_deref()
+            .map(|text| (text, Expression::parse(text)));
+        if let Some((text, Err(err))) = &cargo {
+            warnings.push(
+                self.text("license.cargo-unknown")
+                    .replace("{license}", text)
+                    .replace("{error}", err),
+            );
+        }
+        let Ok(selected) = Expression::parse(self.license()) else {
+            return warnings;
         };
+        for (file, license) in &self.license_files {
+            if !selected.licenses.iter().any(|chosen| chosen.same_family(license)) {
+                warnings.push(
+                    self.text("license.file-differs")
+                        .replace("{file}", file)
+                        .replace("{license}", license.id),
+                );
+            }
+        }
+        if let Some((_, Ok(cargo))) = &cargo {
+            if !cargo.same_as(&selected) {
+                warnings.push(self.text("license.cargo-differs").replace("{license}", &cargo.id()));
+            }
+        }
+        warnings
+    }
+
+    /// Adds the Changelog section before License and Authors, or at the end.
+    fn add_changelog_section(&mut self) {
+        let sections = &mut self.render_options.sections;
+        if sections.contains(&Section::Changelog) {
+            return;
+        }
+        let at = sections
+            .iter()
+            .position(|&section| section == Section::License || section == Section::Authors)
+            .unwrap_or(sections.len());
+        sections.insert(at, Section::Changelog);
+    }
+
+    fn output_label(&self) -> String {
+        if self.to_stdout {
+            String::from("-")
+        } else {
+            self.output_path.display().to_string()
+        }
+    }
+
+    /// Directory relative links in the README are resolved against.
+    fn project_dir(&self) -> &Path {
+        self.output_path.parent().unwrap_or(Path::new(""))
+    }
+
+    /// Lint and link-check findings for the rendered README.
+    fn check(&self, markdown: &str) -> Vec<lint::Issue> {
+        let mut issues = lint::lint(markdown);
+        // Nothing is written next to the README when it goes to stdout
+        let planned = if self.to_stdout { Vec::new() } else { self.planned_paths() };
+        issues.extend(links::check_planned(markdown, self.project_dir(), &planned));
+        issues.sort_by_key(|issue| issue.line);
+        issues
+    }
+
+    fn generate_preview(&self) -> String {
+        self.render(self.languages[0])
+    }
 
-        let test_steps = if tests.is_empty() || tests[0].is_empty() {
-            String::from("1. <Test instructions>")
+    fn render(&self, lang: Lang) -> String {
+        // Only the first language goes to stdout, with no files to switch to
+        let translations = if self.languages.len() > 1 && !self.to_stdout {
+            self.targets()
+                .into_iter()
+                .map(|(lang, path)| {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    (lang, name.into_owned())
+                })
+                .collect()
         } else {
-            tests.iter()
-                .enumerate()
-                .map(|(i, step)| format!("{}. {}", i + 1, step.trim()))
-                .collect::<Vec<_>>()
-                .join("\n")
+            Vec::new()
+        };
+        let options = render::Options {
+            lang,
+            translations,
+            ..self.render_options.clone()
         };
+        render::render(&self.fields, self.license(), &options)
+    }
 
-        format!(
-r#"<div align="center">
-
-# {}
-
-{}
-
-{}
-
-[Documentation](#{}) · [Report Bug](https://github.com/{}/issues) · [Request Feature](https://github.com/{}/issues)
-
-{}</div>
-
-## 📋 Table of Contents
-- [About](#about)
-- [Features](#features)
-- [Built With](#built-with)
-- [Getting Started](#getting-started)
-  - [Prerequisites](#prerequisites)
-  - [Installation](#installation)
-- [Usage](#usage)
-- [API Documentation](#api-documentation)
-- [Testing](#testing)
-- [Contributing](#contributing)
-- [License](#license)
-- [Contact](#contact)
-
-## 🔍 About
-{}
-
-## ✨ Features
-{}
-
-## 🛠️ Built With
-{}
-
-## 🚀 Getting Started
-
-### Prerequisites
-{}
-
-### Installation
-{}
-
-## 💡 Usage
-```bash
-{}
-```
-
-## 📚 API Documentation
-```
-{}
-```
-
-## 🧪 Testing
-{}
-
-## 🤝 Contributing
-{}
-
-## 📝 License
-This project is licensed under the {} - see the [LICENSE](LICENSE) file for details.
-
-## 👥 Authors
-{}
-
----
-<div align="center">
-Made with ❤️ by contributors
-</div>"#,
-            // Title and badges section
-            project_title,
-            short_desc,
-            badges.join("\n"),
-            repo_name,
-            repo_name,
-            repo_name,
-            if !tech_badges.is_empty() { tech_badges.join(" ") } else { String::from("<Technology badges>") },
-            // Main content
-            detailed_desc,
-            features_list,
-            if technologies.is_empty() || technologies[0].is_empty() {
-                String::from("- <Technologies used>")
-            } else {
-                technologies.iter()
-                    .map(|t| format!("- {}", t.trim()))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            },
-            prereq_list,
-            install_steps,
-            usage,
-            api_docs,
-            test_steps,
-            contributing,
-            license,
-            if authors.is_empty() || authors[0].is_empty() {
-                String::from("- <Project authors>")
-            } else {
-                authors.iter()
-                    .map(|a| format!("- {}", a.trim()))
-                    .collect::<Vec<_>>()
-                    .join("\n")
+    /// Applies a loaded configuration on top of the built-in defaults.
+    fn apply_config(&mut self, config: Config) -> Result<(), String> {
+        for (id, value) in config.fields {
+            let field = self
+                .field_mut(&id)
+                .ok_or_else(|| format!("unknown field '{id}' in [fields]"))?;
+            field.value = value;
+        }
+        if let Some(sections) = config.sections {
+            self.render_options.sections =
+                sections.iter().filter_map(|id| Section::from_id(id)).collect();
+            self.sections_pinned = true;
+            self.sync_fields();
+        }
+        if let Some(ids) = config.community {
+            self.set_community(&ids)?;
+        }
+        if let Some(badges) = config.badges {
+            self.render_options.badges =
+                badges.iter().filter_map(|id| Badge::from_id(id)).collect();
+            self.badges_pinned = true;
+        }
+        if let Some(id) = config.preset {
+            self.select_preset(&id)?;
+        }
+        if config.emoji == Some(false) {
+            self.render_options.heading_style = HeadingStyle::Plain;
+        }
+        match config.heading_style.as_deref() {
+            Some("emoji") => self.render_options.heading_style = HeadingStyle::Emoji,
+            Some("plain") => self.render_options.heading_style = HeadingStyle::Plain,
+            Some("custom") => self.render_options.heading_style = HeadingStyle::Custom(config.icons),
+            _ => {}
+        }
+        match config.heading_syntax.as_deref() {
+            Some("atx") => self.render_options.heading_syntax = HeadingSyntax::Atx,
+            Some("setext") => self.render_options.heading_syntax = HeadingSyntax::Setext,
+            _ => {}
+        }
+        if let Some(path) = config.template {
+            let template = fs::read_to_string(&path)
+                .map_err(|e| format!("template {}: {e}", path.display()))?;
+            self.render_options.template = Some(template);
+        }
+        if let Some(license) = config.license {
+            self.select_license(&license)?;
+            self.license_pinned = true;
+        }
+        if let Some(output) = config.output {
+            self.set_output(&output);
+        }
+        if let Some(languages) = config.languages {
+            self.set_languages(&languages)?;
+        }
+        if let Some(name) = config.keymap {
+            self.keymap = Keymap::preset(&name).ok_or_else(|| {
+                format!("unknown keymap '{name}', expected one of {}", keymap::PRESETS.join(", "))
+            })?;
+        }
+        if let Some(name) = config.theme {
+            self.theme = Theme::preset(&name).ok_or_else(|| {
+                format!("unknown theme '{name}', expected one of {}", theme::PRESETS.join(", "))
+            })?;
+        }
+        for (id, bindings) in config.keys {
+            let action = Action::from_id(&id).ok_or_else(|| format!("unknown action '{id}' in [keys]"))?;
+            self.keymap.bind(action, &bindings)?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            if let Error::Usage(_) = err {
+                eprintln!("\n{}", cli::USAGE);
             }
-        )
+            ExitCode::from(err.exit_code())
+        }
     }
 }
 
-fn main() -> Result<(), io::Error> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+fn run() -> Result<ExitCode, Error> {
+    let args = Args::parse().map_err(Error::Usage)?;
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(ExitCode::SUCCESS);
+    }
+    if args.lint.is_some() || args.check_links.is_some() {
+        let (path, check_links) = match (&args.lint, &args.check_links) {
+            (Some(path), _) => (path, false),
+            (None, Some(path)) => (path, true),
+            (None, None) => unreachable!(),
+        };
+        let markdown = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{path}: {e}")))?;
+        let issues = if check_links {
+            let base = Path::new(path).parent().unwrap_or(Path::new(""));
+            let mut issues = links::check(&markdown, base);
+            issues.extend(
+                lint::lint(&markdown)
+                    .into_iter()
+                    .filter(|issue| issue.rule.starts_with("MD051")),
+            );
+            issues.sort_by_key(|issue| issue.line);
+            issues
+        } else {
+            lint::lint(&markdown)
+        };
+        for issue in &issues {
+            println!("{path}:{issue}");
+        }
+        if !issues.is_empty() {
+            eprintln!("{} problem(s) found", issues.len());
+            return Ok(ExitCode::FAILURE);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
 
-    let mut app = App::default();
-    let res = run_app(&mut terminal, &mut app);
+    let config = Config::load(args.config.as_deref().map(Path::new)).map_err(Error::Config)?;
+    let ui_lang = config
+        .ui_language
+        .as_deref()
+        .and_then(Lang::from_code)
+        .unwrap_or_else(messages::detect_lang);
+    let mut app = App::new(ui_lang);
+    app.apply_config(config).map_err(Error::Config)?;
+    let dir = env::current_dir()?;
+    if !args.fresh {
+        if let Some(session) = Session::load(&dir) {
+            app.resume(session);
+        }
+    }
+    if let Some(id) = &args.preset {
+        app.select_preset(id).map_err(Error::Config)?;
+    }
+    if let Some(output) = &args.output {
+        app.set_output(output);
+    }
+    if let Some(langs) = &args.lang {
+        let codes: Vec<String> = langs.split(',').map(str::to_string).collect();
+        app.set_languages(&codes).map_err(Error::Config)?;
+    }
+    if let Some(files) = &args.community {
+        let ids: Vec<String> = files.split(',').map(str::to_string).collect();
+        app.set_community(&ids).map_err(Error::Config)?;
+    }
+    if let Some(license) = &args.license {
+        app.select_license(license).map_err(Error::Config)?;
+        app.license_pinned = true;
+    }
+    if args.changelog {
+        app.with_changelog = true;
+        app.add_changelog_section();
+    }
+    app.scan_project();
+    app.to_stdout |= args.stdout;
+    app.force = args.force;
+
+    // With the README going to stdout the interface has to live somewhere
+    // else, otherwise the escape codes end up in the pipe.
+    let outcome = if app.to_stdout {
+        run_tui(io::stderr, &mut app)?
+    } else {
+        run_tui(io::stdout, &mut app)?
+    };
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    if outcome.is_some() {
+        Session::clear(&dir)?;
+    } else if !app.history.is_empty() {
+        app.session(&dir).save()?;
+        eprintln!("{}", app.text("done.session-saved"));
+    }
 
-    if let Ok(true) = res {
-        generate_readme(&app);
+    match outcome {
+        Some(Outcome::Written(reports)) => {
+            for report in reports {
+                let path = report.path.display().to_string();
+                println!("{}", app.text("done.written").replace("{path}", &path));
+                if let Some(backup) = report.backup {
+                    let backup = backup.display().to_string();
+                    println!("{}", app.text("done.backup").replace("{path}", &backup));
+                }
+            }
+            for warning in app.license_warnings() {
+                eprintln!("warning: {warning}");
+            }
+        }
+        Some(Outcome::Stdout) => {
+            let mut stdout = io::stdout().lock();
+            stdout.write_all(app.generate_preview().as_bytes())?;
+        }
+        None => {}
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+/// How an interactive session ended when the user asked for output.
+enum Outcome {
+    Written(Vec<WriteReport>),
+    Stdout,
+}
+
+/// Runs the wizard on the stream `open` returns; the terminal is restored
+/// before this returns, panics included.
+fn run_tui<W: Write + 'static>(open: fn() -> W, app: &mut App) -> Result<Option<Outcome>, Error> {
+    let mut terminal = terminal::Guard::new(open).map_err(Error::Terminal)?;
+    run_app(&mut terminal, app).map_err(Error::Terminal)
 }
 
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
-) -> io::Result<bool> {
+) -> io::Result<Option<Outcome>> {
     loop {
-        terminal.draw(|f| ui(f, app))?;
-
-        if let Event::Key(key) = event::read()? {
-            match app.input_mode {
-                InputMode::Navigation => match key.code {
-                    KeyCode::Char('q') => {
-                        return Ok(false);
+        let mut regions = Regions::default();
+        terminal.draw(|f| regions = ui(f, app))?;
+        app.regions = regions;
+
+        let event = match event::read()? {
+            Event::Key(event) => event,
+            Event::Mouse(event) => {
+                if matches!(app.input_mode, InputMode::Navigation | InputMode::Palette) {
+                    if let Some(outcome) = click(app, event) {
+                        return Ok(outcome);
                     }
-                    KeyCode::Down => {
-                        if app.current_field < app.fields.len() - 1 {
-                            app.current_field += 1;
-                        }
-                    }
-                    KeyCode::Up => {
-                        if app.current_field > 0 {
-                            app.current_field -= 1;
+                }
+                continue;
+            }
+            Event::Resize(..) => {
+                terminal.autoresize()?;
+                continue;
+            }
+            _ => continue,
+        };
+        app.error = None;
+        let context = app.context();
+        let action = match app.keymap.resolve(context, Key::from_event(event)) {
+            Resolved::Action(action) => action,
+            Resolved::Pending => continue,
+            Resolved::Key(key) => {
+                // Unbound keys only matter while typing.
+                if matches!(context, Context::Text | Context::Palette) {
+                    match key.code {
+                        KeyCode::Char(c) => app.input.push(c),
+                        KeyCode::Backspace => {
+                            app.input.pop();
                         }
+                        _ => {}
                     }
-                    KeyCode::Enter => {
-                        app.input_mode = InputMode::Editing;
+                    app.palette_selected = 0;
+                }
+                continue;
+            }
+        };
+
+        match (&app.input_mode, action) {
+            (InputMode::Navigation | InputMode::Editing, Action::Undo) => app.undo(),
+            (InputMode::Navigation | InputMode::Editing, Action::Redo) => app.redo(),
+            (InputMode::Navigation, _) => {
+                if let Some(outcome) = navigate(app, action) {
+                    return Ok(outcome);
+                }
+            }
+            (InputMode::Editing, Action::Commit) => {
+                app.commit_input();
+                let page_fields = app.page_fields(app.current_page());
+                let next = page_fields
+                    .iter()
+                    .position(|&i| i == app.current_field)
+                    .and_then(|p| page_fields.get(p + 1));
+                match next {
+                    Some(&next) => {
+                        app.current_field = next;
                         app.input = app.fields[app.current_field].value.clone();
                     }
-                    KeyCode::Tab => {
-                        if app.all_fields_filled() {
-                            return Ok(true);
-                        }
-                    }
-                    _ => {}
-                },
-                InputMode::Editing => match key.code {
-                    KeyCode::Enter => {
-                        app.fields[app.current_field].value = app.input.drain(..).collect();
-                        if app.current_field < app.fields.len() - 1 {
-                            app.current_field += 1;
-                            app.input = app.fields[app.current_field].value.clone();
-                        } else {
-                            app.input_mode = InputMode::Navigation;
-                        }
-                    }
-                    KeyCode::Char(c) => {
-                        app.input.push(c);
+                    None => app.input_mode = InputMode::Navigation,
+                }
+            }
+            (InputMode::EditingPath, Action::Commit) => {
+                let raw: String = app.input.drain(..).collect();
+                app.set_output(&raw);
+                app.input_mode = InputMode::Navigation;
+                if let Some(outcome) = app.submit() {
+                    return Ok(Some(outcome));
+                }
+            }
+            (InputMode::Editing, Action::Save) => {
+                app.commit_input();
+                app.input_mode = InputMode::Navigation;
+            }
+            (InputMode::EditingPath, Action::Save) => {
+                let raw: String = app.input.drain(..).collect();
+                app.set_output(&raw);
+                app.input_mode = InputMode::Navigation;
+            }
+            (InputMode::Editing | InputMode::EditingPath, Action::Cancel) => {
+                app.input.clear();
+                app.input_mode = InputMode::Navigation;
+            }
+            (InputMode::ConfirmOverwrite, Action::Yes) => match app.write_readme() {
+                Ok(reports) => return Ok(Some(Outcome::Written(reports))),
+                Err(err) => {
+                    app.error = Some(err.to_string());
+                    app.input_mode = InputMode::Navigation;
+                }
+            },
+            (InputMode::ConfirmOverwrite, Action::No) => {
+                app.input_mode = InputMode::Navigation;
+            }
+            (InputMode::Palette, Action::Down) if app.palette_selected + 1 < app.palette().len() => {
+                app.palette_selected += 1;
+            }
+            (InputMode::Palette, Action::Up) => {
+                app.palette_selected = app.palette_selected.saturating_sub(1);
+            }
+            (InputMode::Palette, Action::Commit) => {
+                if let Some(outcome) = run_palette_entry(app, app.palette_selected) {
+                    return Ok(outcome);
+                }
+            }
+            (InputMode::Palette, Action::Save | Action::Cancel) => {
+                app.input.clear();
+                app.input_mode = InputMode::Navigation;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Clicks select, double clicks open, the wheel scrolls the review output
+/// and its checks. `Some` ends the session, like [`navigate`].
+fn click(app: &mut App, event: MouseEvent) -> Option<Option<Outcome>> {
+    let checks_max = app
+        .regions
+        .checks
+        .filter(|&(area, _)| contains(area, event.column, event.row))
+        .map(|(_, max)| max);
+    let over_output = app
+        .regions
+        .scroll
+        .is_some_and(|area| contains(area, event.column, event.row));
+    match event.kind {
+        MouseEventKind::ScrollDown if checks_max.is_some() => {
+            app.checks_scroll = app.checks_scroll.saturating_add(3).min(checks_max.unwrap_or(0));
+        }
+        MouseEventKind::ScrollUp if checks_max.is_some() => {
+            app.checks_scroll = app.checks_scroll.saturating_sub(3);
+        }
+        MouseEventKind::ScrollDown if over_output => {
+            app.review_scroll = app.review_scroll.saturating_add(3);
+        }
+        MouseEventKind::ScrollUp if over_output => {
+            app.review_scroll = app.review_scroll.saturating_sub(3);
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            let target = app.regions.target(event.column, event.row)?;
+            let now = Instant::now();
+            let double = app
+                .last_click
+                .is_some_and(|(at, last)| last == target && now.duration_since(at) < DOUBLE_CLICK);
+            app.last_click = if double { None } else { Some((now, target)) };
+            app.error = None;
+            match target {
+                Target::Page(page) => app.go_to(page),
+                Target::Field(field) => {
+                    app.show_field(field);
+                    if double {
+                        app.input_mode = InputMode::Editing;
+                        app.input = app.fields[field].value.clone();
                     }
-                    KeyCode::Backspace => {
-                        app.input.pop();
+                }
+                Target::Preset(index) => {
+                    app.selected_preset = index;
+                    if double {
+                        navigate(app, Action::Select);
                     }
-                    KeyCode::Esc => {
-                        app.input.clear();
-                        app.input_mode = InputMode::Navigation;
+                }
+                Target::License(index) => {
+                    app.selected_license = index;
+                    app.license_pinned = true;
+                    if double {
+                        navigate(app, Action::Select);
                     }
-                    _ => {}
-                },
+                }
+                Target::Palette(rank) => return run_palette_entry(app, rank),
             }
         }
+        _ => {}
     }
+    None
 }
 
-fn ui<B: ratatui::backend::Backend>(f: &mut Frame<B>, app: &App) {
+/// Closes the palette and jumps to or runs its match at `rank`. `Some`
+/// ends the session, like [`navigate`].
+fn run_palette_entry(app: &mut App, rank: usize) -> Option<Option<Outcome>> {
+    let entry = app.palette().get(rank).map(|(entry, _, _)| *entry);
+    app.input.clear();
+    app.input_mode = InputMode::Navigation;
+    let review = app.pages().len() - 1;
+    match entry? {
+        Entry::Page(page) => app.go_to(page),
+        Entry::Field(field) => app.show_field(field),
+        Entry::Command(Command::Generate) => return navigate(app, Action::Write),
+        Entry::Command(Command::TogglePreview) => {
+            app.go_to(review);
+            navigate(app, Action::Diff);
+        }
+        Entry::Command(Command::ChangeOutput) => {
+            app.go_to(review);
+            navigate(app, Action::Output);
+        }
+        Entry::Command(Command::ChangeLicense) => app.go_to(1),
+        Entry::Command(Command::ChangePreset) => app.go_to(0),
+        Entry::Command(Command::Undo) => app.undo(),
+        Entry::Command(Command::Redo) => app.redo(),
+        Entry::Command(Command::Quit) => return Some(None),
+    }
+    None
+}
+
+/// Handles `action` on a wizard page. `Some` ends the session, with the
+/// outcome or `None` for quitting.
+fn navigate(app: &mut App, action: Action) -> Option<Option<Outcome>> {
+    let page = app.current_page();
+    let page_fields = app.page_fields(page);
+    let position = page_fields.iter().position(|&i| i == app.current_field);
+    match (page, action) {
+        (_, Action::Quit) => return Some(None),
+        (_, Action::Back) if app.page > 0 => app.go_to(app.page - 1),
+        (_, Action::Write) => return app.submit().map(Some),
+        (_, Action::Palette) => {
+            app.input.clear();
+            app.palette_selected = 0;
+            app.input_mode = InputMode::Palette;
+        }
+        (Page::Preset, Action::Down) if app.selected_preset < preset::PRESETS.len() - 1 => {
+            app.selected_preset += 1;
+        }
+        (Page::Preset, Action::Up) if app.selected_preset > 0 => {
+            app.selected_preset -= 1;
+        }
+        (Page::Preset, Action::Select | Action::Next) => {
+            app.apply_preset(&preset::PRESETS[app.selected_preset]);
+            app.go_to(app.page + 1);
+        }
+        (Page::License, Action::Down) if app.selected_license < app.license_options.len() - 1 => {
+            app.selected_license += 1;
+            app.license_pinned = true;
+        }
+        (Page::License, Action::Up) if app.selected_license > 0 => {
+            app.selected_license -= 1;
+            app.license_pinned = true;
+        }
+        (Page::License, Action::Select | Action::Next) => app.go_to(app.page + 1),
+        (Page::Fields(_), Action::Down) => {
+            if let Some(&next) = position.and_then(|p| page_fields.get(p + 1)) {
+                app.current_field = next;
+            }
+        }
+        (Page::Fields(_), Action::Up) => {
+            if let Some(p) = position.filter(|&p| p > 0) {
+                app.current_field = page_fields[p - 1];
+            }
+        }
+        (Page::Fields(_), Action::Select | Action::Edit) => {
+            app.input_mode = InputMode::Editing;
+            app.input = app.fields[app.current_field].value.clone();
+        }
+        (Page::Fields(_), Action::Next) => app.go_to(app.page + 1),
+        (Page::Review, Action::Select) => return app.submit().map(Some),
+        (Page::Review, Action::Output) => {
+            app.input_mode = InputMode::EditingPath;
+            app.input = app.output_label();
+        }
+        (Page::Review, Action::Diff) => {
+            app.show_diff = !app.show_diff;
+            app.review_scroll = 0;
+        }
+        (Page::Review, Action::Down) => app.review_scroll = app.review_scroll.saturating_add(1),
+        (Page::Review, Action::Up) => app.review_scroll = app.review_scroll.saturating_sub(1),
+        (Page::Review, Action::PageDown) => app.review_scroll = app.review_scroll.saturating_add(10),
+        (Page::Review, Action::PageUp) => app.review_scroll = app.review_scroll.saturating_sub(10),
+        _ => {}
+    }
+    None
+}
+
+fn ui<B: ratatui::backend::Backend>(f: &mut Frame<B>, app: &App) -> Regions {
+    let mut regions = Regions::default();
+    let size = f.size();
+    f.render_widget(Block::default().style(app.theme.text), size);
+    if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+        too_small(f, app);
+        return regions;
+    }
+    let compact = size.width < NARROW_WIDTH || size.height < SHORT_HEIGHT;
+    let page = app.current_page();
+
+    // The help bar grows to fit its hints, as far as the height allows
+    let help = help_text(app, page);
+    let help_width = size.width.saturating_sub(if compact { 2 } else { 6 }).max(1);
+    let help_lines = (help.replace(['{', '}'], "").width() as u16)
+        .div_ceil(help_width)
+        .clamp(1, if compact { 2 } else { 3 });
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .margin(2)
+        .margin(if compact { 0 } else { 2 })
         .constraints(
             [
-                Constraint::Length(3),  // Help text
-                Constraint::Length(3),  // Progress bar
-                Constraint::Min(10),    // Main content
-                Constraint::Length(3),  // Input field
+                Constraint::Length(help_lines + 2), // Help text
+                Constraint::Length(3),              // Progress bar
+                Constraint::Min(4),                 // Main content
+                Constraint::Length(3),              // Input field
             ]
             .as_ref(),
         )
-        .split(f.size());
+        .split(size);
 
     // Help message
-    let (msg, style) = match app.input_mode {
-        InputMode::Navigation => (
-            vec![
-                Span::raw("Press "),
-                Span::styled("↑↓", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to move, "),
-                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to edit, "),
-                Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" for license, "),
-                Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to quit"),
-            ],
-            Style::default(),
+    let (msg, style) = (help_spans(&help, app.theme.strong), app.theme.text);
+    let (msg, style, title) = match &app.error {
+        Some(err) => (
+            vec![Span::raw(err.clone())],
+            app.theme.error,
+            app.text("title.error").to_string(),
         ),
-        InputMode::Editing => (
-            vec![
-                Span::raw("Press "),
-                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to save and continue, "),
-                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to cancel"),
-            ],
-            Style::default(),
+        // Show the start of a key sequence until it is complete
+        None => (
+            msg,
+            style,
+            format!("{} {}", app.text("title.help"), app.keymap.pending()).trim_end().to_string(),
         ),
     };
     let mut text = Text::from(Spans::from(msg));
     text.patch_style(style);
     let help_message = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Help"));
+        .block(app.block(title))
+        .wrap(Wrap { trim: true });
     f.render_widget(help_message, chunks[0]);
 
-    // Progress indicator
-    let progress: Vec<Span> = app
-        .fields
+    // Progress indicator, one dot per wizard step
+    let pages = app.pages();
+    let progress: Vec<Span> = pages
         .iter()
         .enumerate()
-        .map(|(i, field)| {
-            if i == app.current_field {
-                Span::styled(
-                    "●",
-                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
-                )
-            } else if !field.value.is_empty() {
-                Span::styled("●", Style::default().fg(Color::White))
+        .map(|(i, &step)| {
+            let invalid = app
+                .page_fields(step)
+                .iter()
+                .any(|&field| app.fields[field].error().is_some());
+            if i == app.page {
+                Span::styled("●", app.theme.step_current)
+            } else if invalid {
+                Span::styled("●", app.theme.error)
+            } else if app.page_done(i, step) {
+                Span::styled("●", app.theme.step_done)
             } else {
-                Span::styled("○", Style::default().fg(Color::DarkGray))
+                Span::styled("○", app.theme.step_todo)
             }
         })
         .collect();
-    let progress_text = Spans::from(progress);
-    let progress_widget = Paragraph::new(progress_text)
-        .block(Block::default().borders(Borders::ALL).title("Progress"))
+    let progress_title = app
+        .text("title.step")
+        .replace("{n}", &(app.page + 1).to_string())
+        .replace("{total}", &pages.len().to_string())
+        .replace("{name}", &app.page_name(page));
+    let progress_block = app.block(progress_title);
+    let dots = progress_block.inner(chunks[1]);
+    let first_dot = dots.x + dots.width.saturating_sub(pages.len() as u16) / 2;
+    for i in 0..pages.len() as u16 {
+        if first_dot + i < dots.right() {
+            let dot = Rect { x: first_dot + i, width: 1, height: 1, ..dots };
+            regions.targets.push((dot, Target::Page(i as usize)));
+        }
+    }
+    let progress_widget = Paragraph::new(Spans::from(progress))
+        .block(progress_block)
         .alignment(Alignment::Center);
     f.render_widget(progress_widget, chunks[1]);
 
-    // Main content area
-    let main_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
-        .split(chunks[2]);
+    match page {
+        Page::Preset | Page::License => {
+            // Nothing to type on these pages, so they get the input row too
+            let area = Rect {
+                height: chunks[3].y + chunks[3].height - chunks[2].y,
+                ..chunks[2]
+            };
+            if page == Page::Preset {
+                preset_ui(f, app, area, &mut regions);
+            } else {
+                license_ui(f, app, area, &mut regions);
+            }
+        }
+        Page::Fields(_) => fields_ui(f, app, page, chunks[2], &mut regions),
+        Page::Review => review_ui(f, app, chunks[2], &mut regions),
+    }
 
-    // Fields list
-    let fields: Vec<ListItem> = app
-        .fields
+    // Input field
+    if matches!(page, Page::Fields(_) | Page::Review) {
+        let (input_title, input) = match page {
+            Page::Review => (
+                app.text("title.output-path").to_string(),
+                if app.input_mode == InputMode::EditingPath {
+                    app.input.clone()
+                } else {
+                    app.output_label()
+                },
+            ),
+            _ => (
+                app.text("title.editing")
+                    .replace("{field}", &app.fields[app.current_field].name),
+                // The palette borrows `input` for its query
+                if app.input_mode == InputMode::Palette {
+                    String::new()
+                } else {
+                    app.input.clone()
+                },
+            ),
+        };
+        // Long input scrolls so the end being typed stays visible
+        let input_width = chunks[3].width.saturating_sub(3) as usize;
+        let input = Paragraph::new(tail(&input, input_width))
+            .style(match app.input_mode {
+                InputMode::Editing | InputMode::EditingPath => app.theme.editing,
+                _ => app.theme.text,
+            })
+            .block(app.block(input_title));
+        f.render_widget(input, chunks[3]);
+    }
+
+    if matches!(app.input_mode, InputMode::Editing | InputMode::EditingPath) {
+        f.set_cursor(
+            chunks[3].x + tail(&app.input, chunks[3].width.saturating_sub(3) as usize).width() as u16 + 1,
+            chunks[3].y + 1,
+        );
+    }
+
+    if app.input_mode == InputMode::ConfirmOverwrite {
+        let existing = app.existing_targets();
+        let percent_x = if compact { 90 } else { 60 };
+        let area = centered_rect(percent_x, existing.len() as u16 * 2 + 5, size);
+        let mut text = Vec::new();
+        for path in &existing {
+            text.push(Spans::from(Span::styled(
+                app.text("confirm.exists")
+                    .replace("{path}", &path.display().to_string()),
+                app.theme.strong,
+            )));
+            text.push(Spans::from(
+                app.text("confirm.backup")
+                    .replace("{path}", &output::backup_path(path).display().to_string()),
+            ));
+        }
+        text.push(Spans::from(""));
+        text.push(Spans::from(app.text("confirm.question")));
+        let dialog = Paragraph::new(text)
+            .block(
+                app.block(app.text("title.confirm"))
+                    .border_style(app.theme.warning),
+            )
+            .style(app.theme.text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    if app.input_mode == InputMode::Palette {
+        let percent_x = if compact { 90 } else { 60 };
+        palette_ui(f, app, centered_rect(percent_x, 16, size), &mut regions);
+    }
+    regions
+}
+
+/// The command palette: the query on top and the ranked matches below,
+/// scrolled to keep the highlighted one in view.
+fn palette_ui<B: ratatui::backend::Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    regions: &mut Regions,
+) {
+    // The page underneath is not clickable while the palette is open
+    *regions = Regions::default();
+    let block = app.block(app.text("title.palette")).border_style(app.theme.warning);
+    let inner = block.inner(area);
+    f.render_widget(Clear, area);
+    f.render_widget(block.style(app.theme.text), area);
+    if inner.height < 2 {
+        return;
+    }
+
+    let width = inner.width as usize;
+    let query = tail(&app.input, width.saturating_sub(3));
+    f.render_widget(
+        Paragraph::new(Spans::from(vec![
+            Span::styled("> ", app.theme.strong),
+            Span::styled(query, app.theme.editing),
+        ])),
+        Rect { height: 1, ..inner },
+    );
+    f.set_cursor(inner.x + 2 + query.width() as u16, inner.y);
+
+    let list_area = Rect { y: inner.y + 1, height: inner.height - 1, ..inner };
+    let matches = app.palette();
+    if matches.is_empty() {
+        let empty = Paragraph::new(Span::styled(app.text("palette.no-matches"), app.theme.muted));
+        f.render_widget(empty, list_area);
+        return;
+    }
+    let rows = list_area.height as usize;
+    let offset = app.palette_selected.saturating_sub(rows.saturating_sub(1));
+    let items: Vec<ListItem> = matches
         .iter()
         .enumerate()
-        .map(|(i, field)| {
-            let style = if i == app.current_field {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        .skip(offset)
+        .take(rows)
+        .map(|(rank, (_, label, detail))| {
+            let (marker, style) = if rank == app.palette_selected {
+                ("> ", app.theme.selected)
             } else {
-                Style::default()
+                ("  ", app.theme.text)
             };
-            let header = Spans::from(vec![
-                Span::styled(&field.name, style),
-                Span::raw(": "),
-                Span::styled(
-                    if field.value.is_empty() {
-                        "<empty>"
-                    } else {
-                        &field.value
-                    },
-                    if field.value.is_empty() {
-                        Style::default().fg(Color::DarkGray)
-                    } else {
-                        Style::default()
-                    },
-                ),
-            ]);
-            ListItem::new(vec![header])
+            let label = ellipsize(label, width.saturating_sub(2));
+            let detail = ellipsize(detail, width.saturating_sub(label.width() + 4));
+            ListItem::new(Spans::from(vec![
+                Span::styled(format!("{marker}{label}"), style),
+                Span::raw("  "),
+                Span::styled(detail.into_owned(), app.theme.muted),
+            ]))
         })
         .collect();
+    f.render_widget(List::new(items), list_area);
+    for (row, rank) in (offset..matches.len()).take(rows).enumerate() {
+        let row = Rect { y: list_area.y + row as u16, height: 1, ..list_area };
+        regions.targets.push((row, Target::Palette(rank)));
+    }
+}
+
+/// The preset list with the details of the highlighted preset beside it.
+fn preset_ui<B: ratatui::backend::Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    regions: &mut Regions,
+) {
+    let chunks = panes(area, 40, preset::PRESETS.len() as u16);
 
+    let items: Vec<ListItem> = preset::PRESETS
+        .iter()
+        .enumerate()
+        .map(|(i, preset)| {
+            let name = ellipsize(
+                app.text(&format!("preset.{}.name", preset.id)),
+                chunks[0].width.saturating_sub(4) as usize,
+            );
+            if i == app.selected_preset {
+                ListItem::new(Span::styled(
+                    format!("> {name}"),
+                    app.theme.selected,
+                ))
+            } else {
+                ListItem::new(format!("  {name}"))
+            }
+        })
+        .collect();
+    let list = List::new(items).block(
+        app.block(app.text("title.presets")),
+    );
+    f.render_widget(list, chunks[0]);
+    regions.list(chunks[0], (0..preset::PRESETS.len()).map(|i| (1, Target::Preset(i))));
+
+    let preset = &preset::PRESETS[app.selected_preset];
+    let sections: Vec<&str> = preset
+        .sections
+        .iter()
+        .map(|section| i18n::readme(app.ui_lang, section.id()))
+        .collect();
+    let badges: Vec<&str> = preset.badges.iter().map(|badge| badge.id()).collect();
+    let text = vec![
+        Spans::from(app.text(&format!("preset.{}.description", preset.id))),
+        Spans::from(""),
+        Spans::from(
+            app.text("preset.sections")
+                .replace("{sections}", &sections.join(", ")),
+        ),
+        Spans::from(Span::styled(
+            format!("[{}]", badges.join("] [")),
+            app.theme.muted,
+        )),
+    ];
+    let details = Paragraph::new(text)
+        .block(app.block(app.text("title.description")))
+        .wrap(Wrap { trim: true });
+    f.render_widget(details, chunks[1]);
+}
+
+/// The license list with the README's License section for the highlighted
+/// license beside it.
+fn license_ui<B: ratatui::backend::Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    regions: &mut Regions,
+) {
+    let chunks = panes(area, 40, app.license_options.len() as u16);
+
+    // Scroll just far enough to keep the highlighted license in view
+    let rows = chunks[0].height.saturating_sub(2).max(1) as usize;
+    let offset = (app.selected_license + 1).saturating_sub(rows);
+    let items: Vec<ListItem> = app
+        .license_options
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .map(|(i, license)| {
+            let label = match License::find(license) {
+                Some(license) => format!("{:<17} {}", license.id, license.name),
+                None => license.clone(),
+            };
+            let license = ellipsize(&label, chunks[0].width.saturating_sub(4) as usize);
+            if i == app.selected_license {
+                ListItem::new(Span::styled(
+                    format!("> {license}"),
+                    app.theme.selected,
+                ))
+            } else {
+                ListItem::new(format!("  {license}"))
+            }
+        })
+        .collect();
+    let list = List::new(items).block(
+        app.block(app.text("title.license")),
+    );
+    f.render_widget(list, chunks[0]);
+    regions.list(chunks[0], (offset..app.license_options.len()).map(|i| (1, Target::License(i))));
+
+    let mut text = Text::raw(render::license_text(app.languages[0], app.license()));
+    let warnings = app.license_warnings();
+    if !warnings.is_empty() {
+        text.extend(Text::raw(""));
+        for warning in warnings {
+            text.extend(Text::styled(warning, app.theme.warning));
+        }
+    }
+    let preview = Paragraph::new(text)
+        .block(app.block(app.text("title.preview")))
+        .wrap(Wrap { trim: true });
+    f.render_widget(preview, chunks[1]);
+}
+
+/// A section page: its fields on the left, the selected field's
+/// description and example on the right.
+fn fields_ui<B: ratatui::backend::Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    page: Page,
+    area: Rect,
+    regions: &mut Regions,
+) {
+    let rows = app.page_fields(page).into_iter().map(|i| field_height(&app.fields[i])).sum();
+    let chunks = panes(area, 40, rows);
+
+    let items: Vec<ListItem> = app
+        .page_fields(page)
+        .into_iter()
+        .map(|i| field_item(app, &app.fields[i], i == app.current_field, chunks[0].width))
+        .collect();
+    let list = List::new(items).block(
+        app.block(app.page_name(page)),
+    );
+    f.render_widget(list, chunks[0]);
+    regions.list(
+        chunks[0],
+        app.page_fields(page)
+            .into_iter()
+            .map(|i| (field_height(&app.fields[i]), Target::Field(i))),
+    );
+
+    let current_field = &app.fields[app.current_field];
+    let mut text = Text::styled(
+        current_field.name.as_str(),
+        app.theme.strong,
+    );
+    text.extend(Text::from(""));
+    text.extend(Text::from(current_field.description.as_str()));
+    if let Some(example) = app.example(current_field) {
+        text.extend(Text::from(""));
+        text.extend(Text::styled(
+            app.text("example").replace("{example}", &example),
+            app.theme.muted,
+        ));
+    }
+    let description = Paragraph::new(text)
+        .block(app.block(app.text("title.description")))
+        .wrap(Wrap { trim: true });
+    f.render_widget(description, chunks[1]);
+}
+
+/// Every field on the left, the rendered README and its check results on
+/// the right.
+fn review_ui<B: ratatui::backend::Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    regions: &mut Regions,
+) {
+    let rows = app.fields.iter().map(field_height).sum();
+    let main_chunks = panes(area, 50, rows);
+
+    let fields: Vec<ListItem> = app
+        .fields
+        .iter()
+        .map(|field| field_item(app, field, false, main_chunks[0].width))
+        .collect();
     let fields_list = List::new(fields).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("README Sections"),
+        app.block(app.text("title.sections")),
     );
     f.render_widget(fields_list, main_chunks[0]);
+    regions.list(
+        main_chunks[0],
+        app.fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field_height(field), Target::Field(i))),
+    );
 
-    // Right panel: Description or Preview
-    let right_panel = if app.all_fields_filled() {
-        // Show preview when all fields are filled
-        Paragraph::new(app.generate_preview())
-            .block(Block::default().borders(Borders::ALL).title("README Preview"))
-            .wrap(Wrap { trim: true })
+    let mut preview_area = main_chunks[1];
+    let preview = app.generate_preview();
+    let issues = app.check(&preview);
+    if !issues.is_empty() {
+        let lint_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Min(5),
+                    Constraint::Length((issues.len() as u16 + 2).min((main_chunks[1].height / 3).max(8))),
+                ]
+                .as_ref(),
+            )
+            .split(main_chunks[1]);
+        preview_area = lint_chunks[0];
+        // Too many issues for the panel scroll with the wheel
+        let visible = lint_chunks[1].height.saturating_sub(2);
+        let max_scroll = (issues.len() as u16).saturating_sub(visible);
+        let items: Vec<ListItem> = issues
+            .iter()
+            .skip(app.checks_scroll.min(max_scroll) as usize)
+            .map(|issue| {
+                ListItem::new(Spans::from(vec![
+                    Span::styled(
+                        format!("{:>4} ", issue.line),
+                        app.theme.muted,
+                    ),
+                    Span::styled(issue.rule, app.theme.warning),
+                    Span::raw(" "),
+                    Span::raw(issue.message.clone()),
+                ]))
+            })
+            .collect();
+        let lint_list = List::new(items).block(
+            app.block(
+                    app.text("title.checks")
+                        .replace("{n}", &issues.len().to_string()),
+                ),
+        );
+        f.render_widget(lint_list, lint_chunks[1]);
+        regions.checks = Some((lint_chunks[1], max_scroll));
+    }
+    let existing = !app.to_stdout && !app.existing_targets().is_empty();
+    let (text, title) = if app.show_diff && existing {
+        let diff = app.diff();
+        let text = if diff.is_empty() {
+            Text::styled(app.text("diff.unchanged"), app.theme.muted)
+        } else {
+            Text::from(diff.lines().map(|line| diff_line(&app.theme, line)).collect::<Vec<_>>())
+        };
+        (text, app.text("title.diff"))
     } else {
-        // Show field description when fields are being filled
-        let current_field = &app.fields[app.current_field];
-        Paragraph::new(current_field.description.as_ref())
-            .block(Block::default().borders(Borders::ALL).title("Description"))
-            .wrap(Wrap { trim: true })
+        (Text::from(preview), app.text("title.preview"))
     };
-    f.render_widget(right_panel, main_chunks[1]);
+    let preview = Paragraph::new(text)
+        .block(app.block(title))
+        .wrap(Wrap { trim: true })
+        .scroll((app.review_scroll, 0));
+    f.render_widget(preview, preview_area);
+    regions.scroll = Some(preview_area);
+}
 
-    // Input field
-    let input = Paragraph::new(app.input.as_ref())
-        .style(match app.input_mode {
-            InputMode::Navigation => Style::default(),
-            InputMode::Editing => Style::default().fg(Color::Yellow),
+/// A unified diff line styled by its kind.
+fn diff_line(theme: &Theme, line: &str) -> Spans<'static> {
+    let style = if line.starts_with("+++ ") || line.starts_with("--- ") {
+        theme.strong
+    } else if line.starts_with("@@") {
+        theme.diff_hunk
+    } else if line.starts_with('+') {
+        theme.diff_added
+    } else if line.starts_with('-') {
+        theme.diff_removed
+    } else {
+        theme.text
+    };
+    Spans::from(Span::styled(line.to_string(), style))
+}
+
+/// Rows taken by [`field_item`].
+fn field_height(field: &Field) -> u16 {
+    if field.error().is_some() {
+        2
+    } else {
+        1
+    }
+}
+
+/// `name: value` with the validation error, if any, on a second line, cut
+/// to fit a list `width` columns wide.
+fn field_item<'a>(app: &'a App, field: &'a Field, selected: bool, width: u16) -> ListItem<'a> {
+    let width = width.saturating_sub(2) as usize;
+    let name = ellipsize(&field.name, width.saturating_sub(3));
+    let value_width = width.saturating_sub(name.width() + 2);
+    let style = if selected {
+        app.theme.selected
+    } else {
+        app.theme.text
+    };
+    let header = Spans::from(vec![
+        Span::styled(name, style),
+        Span::raw(": "),
+        Span::styled(
+            if field.value.is_empty() {
+                ellipsize(app.text("empty"), value_width)
+            } else {
+                ellipsize(&field.value, value_width)
+            },
+            if field.value.is_empty() {
+                app.theme.muted
+            } else {
+                app.theme.text
+            },
+        ),
+    ]);
+    match field.error() {
+        Some(err) => ListItem::new(vec![
+            header,
+            Spans::from(Span::styled(
+                ellipsize(&format!("  ✗ {err}"), width).into_owned(),
+                app.theme.error,
+            )),
+        ]),
+        None => ListItem::new(vec![header]),
+    }
+}
+
+/// The key hints for the current page and mode, from the active keymap.
+fn help_text(app: &App, page: Page) -> String {
+    let context = app.context();
+    let entries: &[(&[Action], &str)] = match (&app.input_mode, page) {
+        (InputMode::Editing, _) => &[
+            (&[Action::Commit], "key.save"),
+            (&[Action::Save], "key.done"),
+            (&[Action::Cancel], "key.cancel"),
+            (&[Action::Undo], "key.undo"),
+            (&[Action::Redo], "key.redo"),
+        ],
+        (InputMode::EditingPath, _) => &[
+            (&[Action::Commit], "key.write-path"),
+            (&[Action::Cancel], "key.cancel"),
+        ],
+        (InputMode::ConfirmOverwrite, _) => &[
+            (&[Action::Yes], "key.overwrite"),
+            (&[Action::No], "key.back"),
+        ],
+        (InputMode::Palette, _) => &[
+            (&[Action::Up, Action::Down], "key.choose"),
+            (&[Action::Commit], "key.go"),
+            (&[Action::Cancel], "key.cancel"),
+        ],
+        (InputMode::Navigation, Page::Preset) => &[
+            (&[Action::Up, Action::Down], "key.choose"),
+            (&[Action::Select], "key.continue"),
+            (&[Action::Quit], "key.quit"),
+        ],
+        (InputMode::Navigation, Page::License) => &[
+            (&[Action::Up, Action::Down], "key.choose"),
+            (&[Action::Select], "key.continue"),
+            (&[Action::Back], "key.back"),
+            (&[Action::Quit], "key.quit"),
+        ],
+        (InputMode::Navigation, Page::Fields(_)) => &[
+            (&[Action::Up, Action::Down], "key.move"),
+            (&[Action::Select], "key.edit"),
+            (&[Action::Next], "key.next"),
+            (&[Action::Back], "key.back"),
+            (&[Action::Undo], "key.undo"),
+            (&[Action::Redo], "key.redo"),
+            (&[Action::Palette], "key.palette"),
+            (&[Action::Quit], "key.quit"),
+        ],
+        (InputMode::Navigation, Page::Review) => &[
+            (&[Action::Select], "key.write"),
+            (&[Action::Output], "key.write-elsewhere"),
+            (&[Action::Diff], "key.diff"),
+            (&[Action::Up, Action::Down], "key.scroll"),
+            (&[Action::Palette], "key.palette"),
+            (&[Action::Back], "key.back"),
+            (&[Action::Quit], "key.cancel"),
+        ],
+    };
+    entries
+        .iter()
+        .filter_map(|(actions, label)| {
+            let keys = actions
+                .iter()
+                .map(|&action| app.keymap.label(context, action))
+                .collect::<Option<Vec<_>>>()?;
+            let keys = if keys.iter().all(|key| key == "↑" || key == "↓") {
+                keys.concat()
+            } else {
+                keys.join("/")
+            };
+            Some(format!("{{{keys}}} {}", app.text(label)))
         })
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("Editing: {}", app.fields[app.current_field].name)),
-        );
-    f.render_widget(input, chunks[3]);
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
-    if app.input_mode == InputMode::Editing {
-        f.set_cursor(
-            chunks[3].x + app.input.len() as u16 + 1,
-            chunks[3].y + 1,
-        );
+/// Splits a help text into spans, with `{key}` names in `key_style`.
+fn help_spans(template: &str, key_style: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        spans.push(Span::raw(rest[..start].to_string()));
+        spans.push(Span::styled(
+            rest[start + 1..start + len].to_string(),
+            key_style,
+        ));
+        rest = &rest[start + len + 1..];
     }
+    spans.push(Span::raw(rest.to_string()));
+    spans
 }
 
-fn generate_readme(app: &App) {
-    let markdown_content = format!(
-        r#"<div align="center">
-
-# {}
-
-{}
-
-[![Stars](https://img.shields.io/github/stars/{}?style=flat-square)](https://github.com/{}/stargazers)
-[![Forks](https://img.shields.io/github/forks/{}?style=flat-square)](https://github.com/{}/network/members)
-[![Issues](https://img.shields.io/github/issues/{}?style=flat-square)](https://github.com/{}/issues)
-[![License](https://img.shields.io/github/license/{}?style=flat-square)](https://github.com/{}/blob/main/LICENSE)
-
-[Documentation](#{}) · [Report Bug](https://github.com/{}/issues) · [Request Feature](https://github.com/{}/issues)
-
-</div>
-
-## 📋 Table of Contents
-- [About](#about)
-- [Features](#features)
-- [Built With](#built-with)
-- [Getting Started](#getting-started)
-  - [Prerequisites](#prerequisites)
-  - [Installation](#installation)
-- [Usage](#usage)
-- [API Documentation](#api-documentation)
-- [Testing](#testing)
-- [Contributing](#contributing)
-- [License](#license)
-- [Contact](#contact)
-
-## 🔍 About
-{}
-
-## ✨ Features
-{}
-
-## 🛠️ Built With
-{}
-
-## 🚀 Getting Started
-
-### Prerequisites
-{}
-
-### Installation
-{}
-
-## 💡 Usage
-```bash
-{}
-```
-
-## 📚 API Documentation
-```
-{}
-```
-
-## 🧪 Testing
-{}
-
-## 🤝 Contributing
-{}
-
-## 📝 License
-This project is licensed under the {} - see the [LICENSE](LICENSE) file for details.
-
-## 👥 Authors
-{}
-
----
-<div align="center">
-Made with ❤️ by contributors
-</div>"#,
-        app.fields[1].value,  // project_title (1)
-        app.fields[2].value,  // short_description (2)
-        app.fields[0].value,  // repository_name (3)
-        app.fields[0].value,  // repository_name (4)
-        app.fields[0].value,  // repository_name (5)
-        app.fields[0].value,  // repository_name (6)
-        app.fields[0].value,  // repository_name (7)
-        app.fields[0].value,  // repository_name (8)
-        app.fields[0].value,  // repository_name (9)
-        app.fields[0].value,  // repository_name (10)
-        app.fields[0].value,  // repository_name (11)
-        app.fields[0].value,  // repository_name (12)
-        app.fields[0].value,  // repository_name (13)
-        app.fields[3].value,  // detailed_description (14)
-        app.fields[4].value,  // features (15)
-        app.fields[5].value,  // technologies (16)
-        app.fields[6].value,  // prerequisites (17)
-        app.fields[7].value,  // installation (18)
-        app.fields[8].value,  // usage (19)
-        app.fields[9].value,  // api_docs (20)
-        app.fields[11].value, // tests (21)
-        app.fields[10].value, // contributing (22)
-        app.license_options[app.selected_license], // license (23)
-        app.fields[12].value  // authors (24)
-    );
+/// Splits a two-pane page into a list taking `list_percent` of `area` and
+/// the details beside it. When the terminal is narrow the details go below
+/// instead, with the list as tall as its `list_rows` need but no more than
+/// two thirds of the area.
+fn panes(area: Rect, list_percent: u16, list_rows: u16) -> Rc<[Rect]> {
+    let layout = if area.width < NARROW_WIDTH {
+        let list_height = (list_rows + 2).min(area.height * 2 / 3);
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(list_height), Constraint::Min(0)].as_ref())
+    } else {
+        Layout::default().direction(Direction::Horizontal).constraints(
+            [
+                Constraint::Percentage(list_percent),
+                Constraint::Percentage(100 - list_percent),
+            ]
+            .as_ref(),
+        )
+    };
+    layout.split(area)
+}
+
+/// `text` cut to at most `width` columns, ending in `…` if anything was cut.
+fn ellipsize(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w + 1 > width {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    if width > 0 {
+        out.push('…');
+    }
+    Cow::Owned(out)
+}
 
-    fs::write("README.md", markdown_content).expect("Unable to write file");
+/// The end of `text` that fits in `width` columns.
+fn tail(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (i, c) in text.char_indices().rev() {
+        used += c.width().unwrap_or(0);
+        if used > width {
+            return &text[i + c.len_utf8()..];
+        }
+    }
+    text
+}
+
+/// Shown instead of the wizard when the terminal is below
+/// [`MIN_WIDTH`]×[`MIN_HEIGHT`], rather than a garbled frame.
+fn too_small<B: ratatui::backend::Backend>(f: &mut Frame<B>, app: &App) {
+    let size = f.size();
+    let message = app
+        .text("too-small")
+        .replace("{width}", &size.width.to_string())
+        .replace("{height}", &size.height.to_string())
+        .replace("{min_width}", &MIN_WIDTH.to_string())
+        .replace("{min_height}", &MIN_HEIGHT.to_string());
+    let lines = (message.width() as u16).div_ceil(size.width.max(1)).max(1);
+    let area = centered_rect(100, lines, size);
+    let paragraph = Paragraph::new(message)
+        .style(app.theme.warning)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+/// A rectangle `percent_x` wide and `height` rows tall, centered in `r`.
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    // In u32, as a wide terminal times the percentage overflows u16
+    let width = (u32::from(r.width) * u32::from(percent_x.min(100)) / 100) as u16;
+    let height = height.min(r.height);
+    Rect {
+        x: r.x + (r.width - width) / 2,
+        y: r.y + (r.height - height) / 2,
+        width,
+        height,
+    }
 }
@@ -0,0 +1,815 @@
+//! Message catalog for the TUI.
+//!
+//! The help bar is built from the active keymap and the `key.*` labels;
+//! labels can mark key names with braces, e.g. `{-}`, which the UI renders
+//! in bold. Lookups fall back to English for keys a translation does not
+//! have.
+
+use std::env;
+
+use crate::i18n::Lang;
+
+/// The interface language from the usual POSIX locale variables, in their
+/// order of precedence. `C`/`POSIX` and unknown languages mean English.
+pub fn detect_lang() -> Lang {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| {
+            let tag = value.split(['.', '@']).next().unwrap_or_default().to_string();
+            Lang::from_code(&tag)
+        })
+        .unwrap_or(Lang::En)
+}
+
+pub fn text(lang: Lang, key: &str) -> &'static str {
+    lookup(lang, key).unwrap_or("")
+}
+
+/// Like [`text`], but `None` for keys no catalog has, for optional texts
+/// such as preset specific field prompts.
+pub fn lookup(lang: Lang, key: &str) -> Option<&'static str> {
+    let translated = match lang {
+        Lang::En => None,
+        Lang::Es => ui_es(key),
+        Lang::De => ui_de(key),
+        Lang::Fr => ui_fr(key),
+        Lang::Ja => ui_ja(key),
+        Lang::ZhCn => ui_zh_cn(key),
+    };
+    translated.or_else(|| ui_en(key))
+}
+
+fn ui_en(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "Repository Name",
+        "field.repository.description" => "The name of your project/repository (e.g., username/repo)",
+        "field.title.name" => "Project Title",
+        "field.title.description" => "A catchy title for your project",
+        "field.short_description.name" => "Short Description",
+        "field.short_description.description" => "A brief one-line description of your project",
+        "field.description.name" => "Detailed Description",
+        "field.description.description" => "A detailed explanation of what your project does and why it's useful",
+        "field.features.name" => "Features",
+        "field.features.description" => "Key features of your project (separate with semicolons)",
+        "field.technologies.name" => "Technologies",
+        "field.technologies.description" => "Technologies used (separate with semicolons) e.g., React;TypeScript;Node.js",
+        "field.prerequisites.name" => "Prerequisites",
+        "field.prerequisites.description" => "Required software/tools to run your project (separate with semicolons)",
+        "field.installation.name" => "Installation",
+        "field.installation.description" => "Step-by-step installation instructions (separate steps with semicolons)",
+        "field.usage.name" => "Usage Example",
+        "field.usage.description" => "Example code or commands to use your project",
+        "field.api.name" => "API Documentation",
+        "field.api.description" => "Brief API documentation or endpoints (optional)",
+        "field.contributing.name" => "Contributing Guidelines",
+        "field.contributing.description" => "How others can contribute to your project",
+        "field.tests.name" => "Tests",
+        "field.tests.description" => "How to run tests (separate steps with semicolons)",
+        "field.authors.name" => "Authors",
+        "field.authors.description" => "Project authors/maintainers (separate with semicolons)",
+        "title.help" => "Help",
+        "title.error" => "Error",
+        "title.progress" => "Progress",
+        "title.sections" => "README Sections",
+        "title.checks" => "Checks ({n} issues)",
+        "title.preview" => "README Preview",
+        "title.description" => "Description",
+        "title.output-path" => "Output Path",
+        "title.editing" => "Editing: {field}",
+        "title.confirm" => "Confirm Overwrite",
+        "empty" => "<empty>",
+        "confirm.exists" => "{path} already exists.",
+        "confirm.backup" => "The current file will be saved as {path}.",
+        "confirm.question" => "Overwrite? (y/n)",
+        "error.invalid-fields" => "Fix {n} invalid field(s) before generating",
+        "done.written" => "Wrote {path}",
+        "done.backup" => "Previous version saved as {path}",
+        "field.screenshots.name" => "Screenshots",
+        "field.screenshots.description" => "Image paths relative to the README, optionally as caption|path (separate with semicolons)",
+        "field.deployment.name" => "Deployment",
+        "field.deployment.description" => "How to deploy or release the project (separate steps with semicolons)",
+        "preset.generic.name" => "Generic project",
+        "preset.generic.description" => "Every section, for any kind of project",
+        "preset.cli.name" => "CLI tool",
+        "preset.cli.description" => "Command line application: installation and example commands",
+        "preset.rust-lib.name" => "Rust library",
+        "preset.rust-lib.description" => "Crate with crates.io/docs.rs badges and cargo add installation",
+        "preset.library.name" => "Library",
+        "preset.library.description" => "Reusable package with a usage example and API documentation",
+        "preset.web-app.name" => "Web app",
+        "preset.web-app.description" => "Screenshots, local setup and deployment",
+        "preset.api-service.name" => "API service",
+        "preset.api-service.description" => "Endpoints, local setup and deployment",
+        "title.presets" => "Project Type",
+        "example" => "Example: {example}",
+        "preset.sections" => "Sections: {sections}",
+        "preset.cli.prompt.installation" => "How to get the binary: package managers, release downloads or building from source (separate steps with semicolons)",
+        "preset.cli.prompt.usage" => "An example command line showing the tool in action",
+        "preset.rust-lib.prompt.installation" => "How to add the crate to a project (separate steps with semicolons)",
+        "preset.rust-lib.prompt.usage" => "A short Rust snippet using the crate",
+        "preset.rust-lib.prompt.api" => "Where to find the API docs or the main types and functions",
+        "preset.library.prompt.usage" => "A short code example using the library",
+        "preset.web-app.prompt.usage" => "How to start the app locally and where to open it",
+        "preset.web-app.prompt.deployment" => "How the app is built and deployed (separate steps with semicolons)",
+        "preset.api-service.prompt.usage" => "An example request against a running instance",
+        "preset.api-service.prompt.api" => "Main endpoints with method, path and purpose",
+        "title.step" => "Step {n}/{total}: {name}",
+        "title.license" => "License",
+        "title.review" => "Review",
+        "page.project" => "Project",
+        "error.empty-fields" => "Fill in {n} empty field(s) before generating",
+        "title.diff" => "Changes to Existing Files",
+        "diff.unchanged" => "The existing files already match the generated README",
+        "done.session-saved" => "Session saved; run again in this directory to continue, or pass --fresh to start over",
+        "key.choose" => "choose",
+        "key.continue" => "continue",
+        "key.quit" => "quit",
+        "key.back" => "go back",
+        "key.move" => "move",
+        "key.edit" => "edit",
+        "key.next" => "next step",
+        "key.undo" => "undo",
+        "key.redo" => "redo",
+        "key.write" => "write",
+        "key.write-elsewhere" => "write elsewhere",
+        "key.diff" => "diff/output",
+        "key.scroll" => "scroll",
+        "key.cancel" => "cancel",
+        "key.save" => "save and continue",
+        "key.write-path" => "write to this path ({-} for stdout)",
+        "key.overwrite" => "overwrite",
+        "key.done" => "save",
+        "too-small" => "Terminal too small ({width}×{height}). Resize it to at least {min_width}×{min_height}.",
+        "title.palette" => "Go to",
+        "palette.page" => "Page",
+        "palette.command" => "Command",
+        "palette.no-matches" => "No matches",
+        "command.generate" => "Generate README",
+        "command.toggle-preview" => "Toggle preview / diff",
+        "command.change-output" => "Change output path",
+        "command.change-license" => "Change license",
+        "command.change-preset" => "Change project type",
+        "command.undo" => "Undo",
+        "command.redo" => "Redo",
+        "command.quit" => "Quit",
+        "key.palette" => "search",
+        "key.go" => "go",
+        "field.contact_email.name" => "Contact Email",
+        "field.contact_email.description" => "Where conduct and security reports go, written into CODE_OF_CONDUCT.md and SECURITY.md",
+        "license.file-differs" => "{file} on disk is {license}, not the selected license.",
+        "license.cargo-differs" => "Cargo.toml declares {license}, not the selected license.",
+        "license.cargo-unknown" => "Cargo.toml declares {license}, which is not a recognized license ({error}).",
+        _ => return None,
+    })
+}
+
+fn ui_es(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "Nombre del repositorio",
+        "field.repository.description" => "El nombre de tu proyecto/repositorio (p. ej., usuario/repo)",
+        "field.title.name" => "Título del proyecto",
+        "field.title.description" => "Un título llamativo para tu proyecto",
+        "field.short_description.name" => "Descripción corta",
+        "field.short_description.description" => "Una breve descripción de una línea de tu proyecto",
+        "field.description.name" => "Descripción detallada",
+        "field.description.description" => "Una explicación detallada de lo que hace tu proyecto y por qué es útil",
+        "field.features.name" => "Características",
+        "field.features.description" => "Características principales de tu proyecto (separadas por punto y coma)",
+        "field.technologies.name" => "Tecnologías",
+        "field.technologies.description" => "Tecnologías utilizadas (separadas por punto y coma), p. ej., React;TypeScript;Node.js",
+        "field.prerequisites.name" => "Requisitos previos",
+        "field.prerequisites.description" => "Software o herramientas necesarias para ejecutar tu proyecto (separados por punto y coma)",
+        "field.installation.name" => "Instalación",
+        "field.installation.description" => "Instrucciones de instalación paso a paso (separa los pasos con punto y coma)",
+        "field.usage.name" => "Ejemplo de uso",
+        "field.usage.description" => "Código o comandos de ejemplo para usar tu proyecto",
+        "field.api.name" => "Documentación de la API",
+        "field.api.description" => "Documentación breve de la API o endpoints (opcional)",
+        "field.contributing.name" => "Guía de contribución",
+        "field.contributing.description" => "Cómo pueden otros contribuir a tu proyecto",
+        "field.tests.name" => "Pruebas",
+        "field.tests.description" => "Cómo ejecutar las pruebas (separa los pasos con punto y coma)",
+        "field.authors.name" => "Autores",
+        "field.authors.description" => "Autores/mantenedores del proyecto (separados por punto y coma)",
+        "title.help" => "Ayuda",
+        "title.error" => "Error",
+        "title.progress" => "Progreso",
+        "title.sections" => "Secciones del README",
+        "title.checks" => "Comprobaciones ({n} problemas)",
+        "title.preview" => "Vista previa del README",
+        "title.description" => "Descripción",
+        "title.output-path" => "Ruta de salida",
+        "title.editing" => "Editando: {field}",
+        "title.confirm" => "Confirmar sobrescritura",
+        "empty" => "<vacío>",
+        "confirm.exists" => "{path} ya existe.",
+        "confirm.backup" => "El archivo actual se guardará como {path}.",
+        "confirm.question" => "¿Sobrescribir? (y/n)",
+        "error.invalid-fields" => "Corrige {n} campo(s) no válido(s) antes de generar",
+        "done.written" => "Se escribió {path}",
+        "done.backup" => "Versión anterior guardada como {path}",
+        "field.screenshots.name" => "Capturas de pantalla",
+        "field.screenshots.description" => "Rutas de imágenes relativas al README, opcionalmente como título|ruta (separadas por punto y coma)",
+        "field.deployment.name" => "Despliegue",
+        "field.deployment.description" => "Cómo desplegar o publicar el proyecto (separa los pasos con punto y coma)",
+        "preset.generic.name" => "Proyecto genérico",
+        "preset.generic.description" => "Todas las secciones, para cualquier tipo de proyecto",
+        "preset.cli.name" => "Herramienta de línea de comandos",
+        "preset.cli.description" => "Aplicación de terminal: instalación y comandos de ejemplo",
+        "preset.rust-lib.name" => "Biblioteca de Rust",
+        "preset.rust-lib.description" => "Crate con insignias de crates.io/docs.rs e instalación con cargo add",
+        "preset.library.name" => "Biblioteca",
+        "preset.library.description" => "Paquete reutilizable con ejemplo de uso y documentación de la API",
+        "preset.web-app.name" => "Aplicación web",
+        "preset.web-app.description" => "Capturas de pantalla, entorno local y despliegue",
+        "preset.api-service.name" => "Servicio de API",
+        "preset.api-service.description" => "Endpoints, entorno local y despliegue",
+        "title.presets" => "Tipo de proyecto",
+        "example" => "Ejemplo: {example}",
+        "preset.sections" => "Secciones: {sections}",
+        "preset.cli.prompt.installation" => "Cómo obtener el binario: gestores de paquetes, descargas de versiones o compilación desde el código fuente (separa los pasos con punto y coma)",
+        "preset.cli.prompt.usage" => "Una línea de comandos de ejemplo que muestre la herramienta en acción",
+        "preset.rust-lib.prompt.installation" => "Cómo añadir el crate a un proyecto (separa los pasos con punto y coma)",
+        "preset.rust-lib.prompt.usage" => "Un fragmento breve de Rust que use el crate",
+        "preset.rust-lib.prompt.api" => "Dónde encontrar la documentación de la API o los tipos y funciones principales",
+        "preset.library.prompt.usage" => "Un ejemplo de código breve que use la biblioteca",
+        "preset.web-app.prompt.usage" => "Cómo arrancar la aplicación en local y dónde abrirla",
+        "preset.web-app.prompt.deployment" => "Cómo se compila y despliega la aplicación (separa los pasos con punto y coma)",
+        "preset.api-service.prompt.usage" => "Una petición de ejemplo contra una instancia en ejecución",
+        "preset.api-service.prompt.api" => "Endpoints principales con método, ruta y propósito",
+        "title.step" => "Paso {n}/{total}: {name}",
+        "title.license" => "Licencia",
+        "title.review" => "Revisión",
+        "page.project" => "Proyecto",
+        "error.empty-fields" => "Rellena {n} campo(s) vacío(s) antes de generar",
+        "title.diff" => "Cambios en los archivos existentes",
+        "diff.unchanged" => "Los archivos existentes ya coinciden con el README generado",
+        "done.session-saved" => "Sesión guardada; vuelve a ejecutarlo en este directorio para continuar, o usa --fresh para empezar de cero",
+        "key.choose" => "elegir",
+        "key.continue" => "continuar",
+        "key.quit" => "salir",
+        "key.back" => "volver",
+        "key.move" => "moverse",
+        "key.edit" => "editar",
+        "key.next" => "siguiente paso",
+        "key.undo" => "deshacer",
+        "key.redo" => "rehacer",
+        "key.write" => "escribir",
+        "key.write-elsewhere" => "escribir en otra ruta",
+        "key.diff" => "diff/resultado",
+        "key.scroll" => "desplazarse",
+        "key.cancel" => "cancelar",
+        "key.save" => "guardar y continuar",
+        "key.write-path" => "escribir en esta ruta ({-} para stdout)",
+        "key.overwrite" => "sobrescribir",
+        "key.done" => "guardar",
+        "too-small" => "La terminal es demasiado pequeña ({width}×{height}). Agrándala al menos a {min_width}×{min_height}.",
+        "title.palette" => "Ir a",
+        "palette.page" => "Página",
+        "palette.command" => "Comando",
+        "palette.no-matches" => "Sin resultados",
+        "command.generate" => "Generar README",
+        "command.toggle-preview" => "Alternar vista previa / diff",
+        "command.change-output" => "Cambiar ruta de salida",
+        "command.change-license" => "Cambiar licencia",
+        "command.change-preset" => "Cambiar tipo de proyecto",
+        "command.undo" => "Deshacer",
+        "command.redo" => "Rehacer",
+        "command.quit" => "Salir",
+        "key.palette" => "buscar",
+        "key.go" => "ir",
+        "field.contact_email.name" => "Correo de contacto",
+        "field.contact_email.description" => "Dónde se envían los reportes de conducta y de seguridad; se escribe en CODE_OF_CONDUCT.md y SECURITY.md",
+        "license.file-differs" => "{file} en disco es {license}, no la licencia seleccionada.",
+        "license.cargo-differs" => "Cargo.toml declara {license}, no la licencia seleccionada.",
+        "license.cargo-unknown" => "Cargo.toml declara {license}, que no es una licencia reconocida ({error}).",
+        _ => return None,
+    })
+}
+
+fn ui_de(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "Repository-Name",
+        "field.repository.description" => "Der Name deines Projekts/Repositorys (z. B. benutzer/repo)",
+        "field.title.name" => "Projekttitel",
+        "field.title.description" => "Ein einprägsamer Titel für dein Projekt",
+        "field.short_description.name" => "Kurzbeschreibung",
+        "field.short_description.description" => "Eine kurze, einzeilige Beschreibung deines Projekts",
+        "field.description.name" => "Ausführliche Beschreibung",
+        "field.description.description" => "Eine ausführliche Erklärung, was dein Projekt macht und warum es nützlich ist",
+        "field.features.name" => "Funktionen",
+        "field.features.description" => "Die wichtigsten Funktionen deines Projekts (durch Semikolons getrennt)",
+        "field.technologies.name" => "Technologien",
+        "field.technologies.description" => "Verwendete Technologien (durch Semikolons getrennt), z. B. React;TypeScript;Node.js",
+        "field.prerequisites.name" => "Voraussetzungen",
+        "field.prerequisites.description" => "Software/Werkzeuge, die zum Ausführen deines Projekts nötig sind (durch Semikolons getrennt)",
+        "field.installation.name" => "Installation",
+        "field.installation.description" => "Schritt-für-Schritt-Installationsanleitung (Schritte durch Semikolons trennen)",
+        "field.usage.name" => "Anwendungsbeispiel",
+        "field.usage.description" => "Beispielcode oder Befehle zur Verwendung deines Projekts",
+        "field.api.name" => "API-Dokumentation",
+        "field.api.description" => "Kurze API-Dokumentation oder Endpunkte (optional)",
+        "field.contributing.name" => "Richtlinien zum Mitwirken",
+        "field.contributing.description" => "Wie andere zu deinem Projekt beitragen können",
+        "field.tests.name" => "Tests",
+        "field.tests.description" => "Wie die Tests ausgeführt werden (Schritte durch Semikolons trennen)",
+        "field.authors.name" => "Autoren",
+        "field.authors.description" => "Autoren/Maintainer des Projekts (durch Semikolons getrennt)",
+        "title.help" => "Hilfe",
+        "title.error" => "Fehler",
+        "title.progress" => "Fortschritt",
+        "title.sections" => "README-Abschnitte",
+        "title.checks" => "Prüfungen ({n} Probleme)",
+        "title.preview" => "README-Vorschau",
+        "title.description" => "Beschreibung",
+        "title.output-path" => "Ausgabepfad",
+        "title.editing" => "Bearbeiten: {field}",
+        "title.confirm" => "Überschreiben bestätigen",
+        "empty" => "<leer>",
+        "confirm.exists" => "{path} existiert bereits.",
+        "confirm.backup" => "Die aktuelle Datei wird als {path} gesichert.",
+        "confirm.question" => "Überschreiben? (y/n)",
+        "error.invalid-fields" => "Bitte {n} ungültige(s) Feld(er) vor dem Erzeugen korrigieren",
+        "done.written" => "{path} geschrieben",
+        "done.backup" => "Vorherige Version gesichert als {path}",
+        "field.screenshots.name" => "Bildschirmfotos",
+        "field.screenshots.description" => "Bildpfade relativ zur README, optional als Beschriftung|Pfad (durch Semikolons getrennt)",
+        "field.deployment.name" => "Bereitstellung",
+        "field.deployment.description" => "Wie das Projekt bereitgestellt oder veröffentlicht wird (Schritte durch Semikolons trennen)",
+        "preset.generic.name" => "Allgemeines Projekt",
+        "preset.generic.description" => "Alle Abschnitte, für jede Art von Projekt",
+        "preset.cli.name" => "CLI-Werkzeug",
+        "preset.cli.description" => "Kommandozeilenprogramm: Installation und Beispielbefehle",
+        "preset.rust-lib.name" => "Rust-Bibliothek",
+        "preset.rust-lib.description" => "Crate mit crates.io/docs.rs-Badges und Installation per cargo add",
+        "preset.library.name" => "Bibliothek",
+        "preset.library.description" => "Wiederverwendbares Paket mit Anwendungsbeispiel und API-Dokumentation",
+        "preset.web-app.name" => "Web-App",
+        "preset.web-app.description" => "Bildschirmfotos, lokale Einrichtung und Bereitstellung",
+        "preset.api-service.name" => "API-Dienst",
+        "preset.api-service.description" => "Endpunkte, lokale Einrichtung und Bereitstellung",
+        "title.presets" => "Projektart",
+        "example" => "Beispiel: {example}",
+        "preset.sections" => "Abschnitte: {sections}",
+        "preset.cli.prompt.installation" => "Wie man das Programm bekommt: Paketmanager, Release-Downloads oder Bauen aus dem Quellcode (Schritte durch Semikolons trennen)",
+        "preset.cli.prompt.usage" => "Ein Beispielaufruf, der das Werkzeug in Aktion zeigt",
+        "preset.rust-lib.prompt.installation" => "Wie man das Crate zu einem Projekt hinzufügt (Schritte durch Semikolons trennen)",
+        "preset.rust-lib.prompt.usage" => "Ein kurzes Rust-Beispiel, das das Crate verwendet",
+        "preset.rust-lib.prompt.api" => "Wo die API-Dokumentation oder die wichtigsten Typen und Funktionen zu finden sind",
+        "preset.library.prompt.usage" => "Ein kurzes Codebeispiel, das die Bibliothek verwendet",
+        "preset.web-app.prompt.usage" => "Wie man die App lokal startet und wo man sie öffnet",
+        "preset.web-app.prompt.deployment" => "Wie die App gebaut und bereitgestellt wird (Schritte durch Semikolons trennen)",
+        "preset.api-service.prompt.usage" => "Eine Beispielanfrage an eine laufende Instanz",
+        "preset.api-service.prompt.api" => "Wichtigste Endpunkte mit Methode, Pfad und Zweck",
+        "title.step" => "Schritt {n}/{total}: {name}",
+        "title.license" => "Lizenz",
+        "title.review" => "Überprüfen",
+        "page.project" => "Projekt",
+        "error.empty-fields" => "Bitte {n} leere(s) Feld(er) vor dem Erzeugen ausfüllen",
+        "title.diff" => "Änderungen an vorhandenen Dateien",
+        "diff.unchanged" => "Die vorhandenen Dateien entsprechen bereits der erzeugten README",
+        "done.session-saved" => "Sitzung gespeichert; erneut in diesem Verzeichnis starten, um fortzufahren, oder mit --fresh neu beginnen",
+        "key.choose" => "auswählen",
+        "key.continue" => "weiter",
+        "key.quit" => "beenden",
+        "key.back" => "zurück",
+        "key.move" => "bewegen",
+        "key.edit" => "bearbeiten",
+        "key.next" => "nächster Schritt",
+        "key.undo" => "rückgängig",
+        "key.redo" => "wiederholen",
+        "key.write" => "schreiben",
+        "key.write-elsewhere" => "woandershin schreiben",
+        "key.diff" => "Diff/Ausgabe",
+        "key.scroll" => "blättern",
+        "key.cancel" => "abbrechen",
+        "key.save" => "speichern und weiter",
+        "key.write-path" => "an diesen Pfad schreiben ({-} für stdout)",
+        "key.overwrite" => "überschreiben",
+        "key.done" => "speichern",
+        "too-small" => "Terminal zu klein ({width}×{height}). Bitte auf mindestens {min_width}×{min_height} vergrößern.",
+        "title.palette" => "Gehe zu",
+        "palette.page" => "Seite",
+        "palette.command" => "Befehl",
+        "palette.no-matches" => "Keine Treffer",
+        "command.generate" => "README erzeugen",
+        "command.toggle-preview" => "Vorschau / Diff umschalten",
+        "command.change-output" => "Ausgabepfad ändern",
+        "command.change-license" => "Lizenz ändern",
+        "command.change-preset" => "Projekttyp ändern",
+        "command.undo" => "Rückgängig",
+        "command.redo" => "Wiederholen",
+        "command.quit" => "Beenden",
+        "key.palette" => "suchen",
+        "key.go" => "öffnen",
+        "field.contact_email.name" => "Kontakt-E-Mail",
+        "field.contact_email.description" => "Adresse für Verhaltens- und Sicherheitsmeldungen, steht in CODE_OF_CONDUCT.md und SECURITY.md",
+        "license.file-differs" => "{file} auf der Festplatte ist {license}, nicht die gewählte Lizenz.",
+        "license.cargo-differs" => "Cargo.toml gibt {license} an, nicht die gewählte Lizenz.",
+        "license.cargo-unknown" => "Cargo.toml gibt {license} an, was keine bekannte Lizenz ist ({error}).",
+        _ => return None,
+    })
+}
+
+fn ui_fr(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "Nom du dépôt",
+        "field.repository.description" => "Le nom de votre projet/dépôt (p. ex. utilisateur/depot)",
+        "field.title.name" => "Titre du projet",
+        "field.title.description" => "Un titre accrocheur pour votre projet",
+        "field.short_description.name" => "Description courte",
+        "field.short_description.description" => "Une brève description de votre projet en une ligne",
+        "field.description.name" => "Description détaillée",
+        "field.description.description" => "Une explication détaillée de ce que fait votre projet et de son utilité",
+        "field.features.name" => "Fonctionnalités",
+        "field.features.description" => "Les principales fonctionnalités de votre projet (séparées par des points-virgules)",
+        "field.technologies.name" => "Technologies",
+        "field.technologies.description" => "Technologies utilisées (séparées par des points-virgules), p. ex. React;TypeScript;Node.js",
+        "field.prerequisites.name" => "Prérequis",
+        "field.prerequisites.description" => "Logiciels/outils nécessaires pour exécuter votre projet (séparés par des points-virgules)",
+        "field.installation.name" => "Installation",
+        "field.installation.description" => "Instructions d'installation pas à pas (séparez les étapes par des points-virgules)",
+        "field.usage.name" => "Exemple d'utilisation",
+        "field.usage.description" => "Exemple de code ou de commandes pour utiliser votre projet",
+        "field.api.name" => "Documentation de l'API",
+        "field.api.description" => "Brève documentation de l'API ou des endpoints (facultatif)",
+        "field.contributing.name" => "Guide de contribution",
+        "field.contributing.description" => "Comment les autres peuvent contribuer à votre projet",
+        "field.tests.name" => "Tests",
+        "field.tests.description" => "Comment lancer les tests (séparez les étapes par des points-virgules)",
+        "field.authors.name" => "Auteurs",
+        "field.authors.description" => "Auteurs/mainteneurs du projet (séparés par des points-virgules)",
+        "title.help" => "Aide",
+        "title.error" => "Erreur",
+        "title.progress" => "Progression",
+        "title.sections" => "Sections du README",
+        "title.checks" => "Vérifications ({n} problèmes)",
+        "title.preview" => "Aperçu du README",
+        "title.description" => "Description",
+        "title.output-path" => "Chemin de sortie",
+        "title.editing" => "Modification : {field}",
+        "title.confirm" => "Confirmer l'écrasement",
+        "empty" => "<vide>",
+        "confirm.exists" => "{path} existe déjà.",
+        "confirm.backup" => "Le fichier actuel sera sauvegardé sous {path}.",
+        "confirm.question" => "Écraser ? (y/n)",
+        "error.invalid-fields" => "Corrigez {n} champ(s) invalide(s) avant de générer",
+        "done.written" => "{path} écrit",
+        "done.backup" => "Version précédente sauvegardée sous {path}",
+        "field.screenshots.name" => "Captures d'écran",
+        "field.screenshots.description" => "Chemins d'images relatifs au README, éventuellement sous la forme légende|chemin (séparés par des points-virgules)",
+        "field.deployment.name" => "Déploiement",
+        "field.deployment.description" => "Comment déployer ou publier le projet (séparez les étapes par des points-virgules)",
+        "preset.generic.name" => "Projet générique",
+        "preset.generic.description" => "Toutes les sections, pour tout type de projet",
+        "preset.cli.name" => "Outil en ligne de commande",
+        "preset.cli.description" => "Application en terminal : installation et exemples de commandes",
+        "preset.rust-lib.name" => "Bibliothèque Rust",
+        "preset.rust-lib.description" => "Crate avec badges crates.io/docs.rs et installation via cargo add",
+        "preset.library.name" => "Bibliothèque",
+        "preset.library.description" => "Paquet réutilisable avec exemple d'utilisation et documentation de l'API",
+        "preset.web-app.name" => "Application web",
+        "preset.web-app.description" => "Captures d'écran, installation locale et déploiement",
+        "preset.api-service.name" => "Service d'API",
+        "preset.api-service.description" => "Endpoints, installation locale et déploiement",
+        "title.presets" => "Type de projet",
+        "example" => "Exemple : {example}",
+        "preset.sections" => "Sections : {sections}",
+        "preset.cli.prompt.installation" => "Comment obtenir le binaire : gestionnaires de paquets, téléchargement des versions ou compilation depuis les sources (séparez les étapes par des points-virgules)",
+        "preset.cli.prompt.usage" => "Un exemple de ligne de commande qui montre l'outil en action",
+        "preset.rust-lib.prompt.installation" => "Comment ajouter la crate à un projet (séparez les étapes par des points-virgules)",
+        "preset.rust-lib.prompt.usage" => "Un court extrait de Rust utilisant la crate",
+        "preset.rust-lib.prompt.api" => "Où trouver la documentation de l'API ou les principaux types et fonctions",
+        "preset.library.prompt.usage" => "Un court exemple de code utilisant la bibliothèque",
+        "preset.web-app.prompt.usage" => "Comment lancer l'application en local et où l'ouvrir",
+        "preset.web-app.prompt.deployment" => "Comment l'application est compilée et déployée (séparez les étapes par des points-virgules)",
+        "preset.api-service.prompt.usage" => "Un exemple de requête vers une instance en cours d'exécution",
+        "preset.api-service.prompt.api" => "Principaux endpoints avec méthode, chemin et rôle",
+        "title.step" => "Étape {n}/{total} : {name}",
+        "title.license" => "Licence",
+        "title.review" => "Vérification",
+        "page.project" => "Projet",
+        "error.empty-fields" => "Remplissez {n} champ(s) vide(s) avant de générer",
+        "title.diff" => "Modifications des fichiers existants",
+        "diff.unchanged" => "Les fichiers existants correspondent déjà au README généré",
+        "done.session-saved" => "Session enregistrée ; relancez dans ce répertoire pour continuer, ou utilisez --fresh pour recommencer",
+        "key.choose" => "choisir",
+        "key.continue" => "continuer",
+        "key.quit" => "quitter",
+        "key.back" => "revenir",
+        "key.move" => "se déplacer",
+        "key.edit" => "modifier",
+        "key.next" => "étape suivante",
+        "key.undo" => "annuler",
+        "key.redo" => "rétablir",
+        "key.write" => "écrire",
+        "key.write-elsewhere" => "écrire ailleurs",
+        "key.diff" => "diff/résultat",
+        "key.scroll" => "défiler",
+        "key.cancel" => "abandonner",
+        "key.save" => "enregistrer et continuer",
+        "key.write-path" => "écrire à ce chemin ({-} pour stdout)",
+        "key.overwrite" => "écraser",
+        "key.done" => "enregistrer",
+        "too-small" => "Terminal trop petit ({width}×{height}). Agrandissez-le à au moins {min_width}×{min_height}.",
+        "title.palette" => "Aller à",
+        "palette.page" => "Page",
+        "palette.command" => "Commande",
+        "palette.no-matches" => "Aucun résultat",
+        "command.generate" => "Générer le README",
+        "command.toggle-preview" => "Basculer aperçu / diff",
+        "command.change-output" => "Changer le chemin de sortie",
+        "command.change-license" => "Changer de licence",
+        "command.change-preset" => "Changer le type de projet",
+        "command.undo" => "Annuler",
+        "command.redo" => "Rétablir",
+        "command.quit" => "Quitter",
+        "key.palette" => "rechercher",
+        "key.go" => "aller",
+        "field.contact_email.name" => "E-mail de contact",
+        "field.contact_email.description" => "Adresse des signalements de conduite et de sécurité, écrite dans CODE_OF_CONDUCT.md et SECURITY.md",
+        "license.file-differs" => "{file} sur le disque est sous {license}, pas sous la licence choisie.",
+        "license.cargo-differs" => "Cargo.toml déclare {license}, pas la licence choisie.",
+        "license.cargo-unknown" => "Cargo.toml déclare {license}, qui n'est pas une licence reconnue ({error}).",
+        _ => return None,
+    })
+}
+
+fn ui_ja(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "リポジトリ名",
+        "field.repository.description" => "プロジェクト/リポジトリの名前（例: username/repo）",
+        "field.title.name" => "プロジェクトのタイトル",
+        "field.title.description" => "プロジェクトのキャッチーなタイトル",
+        "field.short_description.name" => "短い説明",
+        "field.short_description.description" => "プロジェクトを一行で説明する短い文",
+        "field.description.name" => "詳細な説明",
+        "field.description.description" => "プロジェクトが何をするのか、なぜ役に立つのかの詳しい説明",
+        "field.features.name" => "特徴",
+        "field.features.description" => "プロジェクトの主な特徴（セミコロン区切り）",
+        "field.technologies.name" => "使用技術",
+        "field.technologies.description" => "使用している技術（セミコロン区切り）例: React;TypeScript;Node.js",
+        "field.prerequisites.name" => "前提条件",
+        "field.prerequisites.description" => "プロジェクトの実行に必要なソフトウェア/ツール（セミコロン区切り）",
+        "field.installation.name" => "インストール",
+        "field.installation.description" => "手順ごとのインストール方法（手順はセミコロン区切り）",
+        "field.usage.name" => "使用例",
+        "field.usage.description" => "プロジェクトを使うためのサンプルコードやコマンド",
+        "field.api.name" => "API ドキュメント",
+        "field.api.description" => "簡単な API ドキュメントやエンドポイント（任意）",
+        "field.contributing.name" => "コントリビューションガイド",
+        "field.contributing.description" => "他の人がプロジェクトに貢献する方法",
+        "field.tests.name" => "テスト",
+        "field.tests.description" => "テストの実行方法（手順はセミコロン区切り）",
+        "field.authors.name" => "作者",
+        "field.authors.description" => "プロジェクトの作者/メンテナー（セミコロン区切り）",
+        "title.help" => "ヘルプ",
+        "title.error" => "エラー",
+        "title.progress" => "進捗",
+        "title.sections" => "README のセクション",
+        "title.checks" => "チェック（{n} 件の問題）",
+        "title.preview" => "README プレビュー",
+        "title.description" => "説明",
+        "title.output-path" => "出力先",
+        "title.editing" => "編集中: {field}",
+        "title.confirm" => "上書きの確認",
+        "empty" => "<未入力>",
+        "confirm.exists" => "{path} は既に存在します。",
+        "confirm.backup" => "現在のファイルは {path} として保存されます。",
+        "confirm.question" => "上書きしますか？ (y/n)",
+        "error.invalid-fields" => "生成する前に {n} 件の無効なフィールドを修正してください",
+        "done.written" => "{path} に書き込みました",
+        "done.backup" => "以前のバージョンを {path} として保存しました",
+        "field.screenshots.name" => "スクリーンショット",
+        "field.screenshots.description" => "README からの相対パスで画像を指定。キャプション|パス の形式も可（セミコロン区切り）",
+        "field.deployment.name" => "デプロイ",
+        "field.deployment.description" => "プロジェクトのデプロイ・リリース方法（手順はセミコロン区切り）",
+        "preset.generic.name" => "汎用プロジェクト",
+        "preset.generic.description" => "すべてのセクション。どんなプロジェクトにも",
+        "preset.cli.name" => "CLI ツール",
+        "preset.cli.description" => "コマンドラインアプリ: インストール方法とコマンド例",
+        "preset.rust-lib.name" => "Rust ライブラリ",
+        "preset.rust-lib.description" => "crates.io/docs.rs バッジと cargo add によるインストール",
+        "preset.library.name" => "ライブラリ",
+        "preset.library.description" => "使用例と API ドキュメントを備えた再利用可能なパッケージ",
+        "preset.web-app.name" => "Web アプリ",
+        "preset.web-app.description" => "スクリーンショット、ローカル環境構築、デプロイ",
+        "preset.api-service.name" => "API サービス",
+        "preset.api-service.description" => "エンドポイント、ローカル環境構築、デプロイ",
+        "title.presets" => "プロジェクトの種類",
+        "example" => "例: {example}",
+        "preset.sections" => "セクション: {sections}",
+        "preset.cli.prompt.installation" => "バイナリの入手方法: パッケージマネージャー、リリースのダウンロード、ソースからのビルド（手順はセミコロン区切り）",
+        "preset.cli.prompt.usage" => "ツールの動作を示すコマンドラインの例",
+        "preset.rust-lib.prompt.installation" => "プロジェクトにクレートを追加する方法（手順はセミコロン区切り）",
+        "preset.rust-lib.prompt.usage" => "クレートを使う短い Rust コード",
+        "preset.rust-lib.prompt.api" => "API ドキュメントの場所、または主な型と関数",
+        "preset.library.prompt.usage" => "ライブラリを使う短いコード例",
+        "preset.web-app.prompt.usage" => "アプリをローカルで起動する方法と開く場所",
+        "preset.web-app.prompt.deployment" => "アプリのビルドとデプロイの方法（手順はセミコロン区切り）",
+        "preset.api-service.prompt.usage" => "稼働中のインスタンスへのリクエスト例",
+        "preset.api-service.prompt.api" => "主なエンドポイントのメソッド、パス、用途",
+        "title.step" => "ステップ {n}/{total}: {name}",
+        "title.license" => "ライセンス",
+        "title.review" => "確認",
+        "page.project" => "プロジェクト",
+        "error.empty-fields" => "生成する前に {n} 件の空のフィールドを入力してください",
+        "title.diff" => "既存ファイルへの変更",
+        "diff.unchanged" => "既存のファイルは生成された README と同じです",
+        "done.session-saved" => "セッションを保存しました。このディレクトリで再度実行すると続きから再開します（--fresh で最初から）",
+        "key.choose" => "選択",
+        "key.continue" => "次へ",
+        "key.quit" => "終了",
+        "key.back" => "戻る",
+        "key.move" => "移動",
+        "key.edit" => "編集",
+        "key.next" => "次のステップ",
+        "key.undo" => "元に戻す",
+        "key.redo" => "やり直し",
+        "key.write" => "書き込み",
+        "key.write-elsewhere" => "別の場所に書き込み",
+        "key.diff" => "差分/出力",
+        "key.scroll" => "スクロール",
+        "key.cancel" => "キャンセル",
+        "key.save" => "保存して次へ",
+        "key.write-path" => "この場所に書き込み（{-} で標準出力）",
+        "key.overwrite" => "上書き",
+        "key.done" => "保存",
+        "too-small" => "端末が小さすぎます ({width}×{height})。{min_width}×{min_height} 以上に広げてください。",
+        "title.palette" => "移動",
+        "palette.page" => "ページ",
+        "palette.command" => "コマンド",
+        "palette.no-matches" => "一致なし",
+        "command.generate" => "README を生成",
+        "command.toggle-preview" => "プレビュー / 差分を切り替え",
+        "command.change-output" => "出力先を変更",
+        "command.change-license" => "ライセンスを変更",
+        "command.change-preset" => "プロジェクトの種類を変更",
+        "command.undo" => "元に戻す",
+        "command.redo" => "やり直す",
+        "command.quit" => "終了",
+        "key.palette" => "検索",
+        "key.go" => "移動",
+        "field.contact_email.name" => "連絡先メールアドレス",
+        "field.contact_email.description" => "行動規範やセキュリティに関する報告の送り先。CODE_OF_CONDUCT.md と SECURITY.md に記載されます",
+        "license.file-differs" => "既存の {file} は {license} で、選択したライセンスと異なります。",
+        "license.cargo-differs" => "Cargo.toml は {license} を宣言しており、選択したライセンスと異なります。",
+        "license.cargo-unknown" => "Cargo.toml は {license} を宣言していますが、認識できないライセンスです（{error}）。",
+        _ => return None,
+    })
+}
+
+fn ui_zh_cn(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "field.repository.name" => "仓库名称",
+        "field.repository.description" => "项目/仓库的名称（例如 username/repo）",
+        "field.title.name" => "项目标题",
+        "field.title.description" => "为项目起一个吸引人的标题",
+        "field.short_description.name" => "简短描述",
+        "field.short_description.description" => "用一句话简要描述你的项目",
+        "field.description.name" => "详细描述",
+        "field.description.description" => "详细说明你的项目做什么以及为什么有用",
+        "field.features.name" => "功能特性",
+        "field.features.description" => "项目的主要功能（用分号分隔）",
+        "field.technologies.name" => "技术栈",
+        "field.technologies.description" => "使用的技术（用分号分隔），例如 React;TypeScript;Node.js",
+        "field.prerequisites.name" => "环境要求",
+        "field.prerequisites.description" => "运行项目所需的软件/工具（用分号分隔）",
+        "field.installation.name" => "安装",
+        "field.installation.description" => "分步安装说明（步骤之间用分号分隔）",
+        "field.usage.name" => "使用示例",
+        "field.usage.description" => "使用项目的示例代码或命令",
+        "field.api.name" => "API 文档",
+        "field.api.description" => "简要的 API 文档或接口（可选）",
+        "field.contributing.name" => "贡献指南",
+        "field.contributing.description" => "其他人如何为你的项目做贡献",
+        "field.tests.name" => "测试",
+        "field.tests.description" => "如何运行测试（步骤之间用分号分隔）",
+        "field.authors.name" => "作者",
+        "field.authors.description" => "项目作者/维护者（用分号分隔）",
+        "title.help" => "帮助",
+        "title.error" => "错误",
+        "title.progress" => "进度",
+        "title.sections" => "README 章节",
+        "title.checks" => "检查（{n} 个问题）",
+        "title.preview" => "README 预览",
+        "title.description" => "说明",
+        "title.output-path" => "输出路径",
+        "title.editing" => "正在编辑：{field}",
+        "title.confirm" => "确认覆盖",
+        "empty" => "<空>",
+        "confirm.exists" => "{path} 已存在。",
+        "confirm.backup" => "当前文件将另存为 {path}。",
+        "confirm.question" => "是否覆盖？(y/n)",
+        "error.invalid-fields" => "生成前请先修正 {n} 个无效字段",
+        "done.written" => "已写入 {path}",
+        "done.backup" => "旧版本已保存为 {path}",
+        "field.screenshots.name" => "截图",
+        "field.screenshots.description" => "相对于 README 的图片路径，也可写成 标题|路径（用分号分隔）",
+        "field.deployment.name" => "部署",
+        "field.deployment.description" => "如何部署或发布项目（步骤之间用分号分隔）",
+        "preset.generic.name" => "通用项目",
+        "preset.generic.description" => "包含所有章节，适用于任何项目",
+        "preset.cli.name" => "命令行工具",
+        "preset.cli.description" => "命令行应用：安装方法与示例命令",
+        "preset.rust-lib.name" => "Rust 库",
+        "preset.rust-lib.description" => "带 crates.io/docs.rs 徽章，使用 cargo add 安装",
+        "preset.library.name" => "库",
+        "preset.library.description" => "可复用的包，含使用示例和 API 文档",
+        "preset.web-app.name" => "Web 应用",
+        "preset.web-app.description" => "截图、本地搭建与部署",
+        "preset.api-service.name" => "API 服务",
+        "preset.api-service.description" => "接口、本地搭建与部署",
+        "title.presets" => "项目类型",
+        "example" => "示例：{example}",
+        "preset.sections" => "章节：{sections}",
+        "preset.cli.prompt.installation" => "获取可执行文件的方式：包管理器、发布版下载或从源码构建（步骤之间用分号分隔）",
+        "preset.cli.prompt.usage" => "展示工具用法的示例命令行",
+        "preset.rust-lib.prompt.installation" => "如何将该 crate 添加到项目中（步骤之间用分号分隔）",
+        "preset.rust-lib.prompt.usage" => "一段使用该 crate 的简短 Rust 代码",
+        "preset.rust-lib.prompt.api" => "API 文档的位置，或主要的类型和函数",
+        "preset.library.prompt.usage" => "一段使用该库的简短代码示例",
+        "preset.web-app.prompt.usage" => "如何在本地启动应用以及在哪里打开",
+        "preset.web-app.prompt.deployment" => "应用的构建与部署方式（步骤之间用分号分隔）",
+        "preset.api-service.prompt.usage" => "一个针对运行中实例的示例请求",
+        "preset.api-service.prompt.api" => "主要端点及其方法、路径和用途",
+        "title.step" => "第 {n}/{total} 步：{name}",
+        "title.license" => "许可证",
+        "title.review" => "检查",
+        "page.project" => "项目",
+        "error.empty-fields" => "生成前请先填写 {n} 个空字段",
+        "title.diff" => "对现有文件的更改",
+        "diff.unchanged" => "现有文件已与生成的 README 一致",
+        "done.session-saved" => "会话已保存；在此目录再次运行即可继续，或使用 --fresh 重新开始",
+        "key.choose" => "选择",
+        "key.continue" => "继续",
+        "key.quit" => "退出",
+        "key.back" => "返回",
+        "key.move" => "移动",
+        "key.edit" => "编辑",
+        "key.next" => "下一步",
+        "key.undo" => "撤销",
+        "key.redo" => "重做",
+        "key.write" => "写入",
+        "key.write-elsewhere" => "写入其他位置",
+        "key.diff" => "差异/输出",
+        "key.scroll" => "滚动",
+        "key.cancel" => "取消",
+        "key.save" => "保存并继续",
+        "key.write-path" => "写入此路径（{-} 表示标准输出）",
+        "key.overwrite" => "覆盖",
+        "key.done" => "保存",
+        "too-small" => "终端窗口太小 ({width}×{height})。请调整到至少 {min_width}×{min_height}。",
+        "title.palette" => "跳转",
+        "palette.page" => "页面",
+        "palette.command" => "命令",
+        "palette.no-matches" => "无匹配项",
+        "command.generate" => "生成 README",
+        "command.toggle-preview" => "切换预览 / 差异",
+        "command.change-output" => "更改输出路径",
+        "command.change-license" => "更改许可证",
+        "command.change-preset" => "更改项目类型",
+        "command.undo" => "撤销",
+        "command.redo" => "重做",
+        "command.quit" => "退出",
+        "key.palette" => "搜索",
+        "key.go" => "跳转",
+        "field.contact_email.name" => "联系邮箱",
+        "field.contact_email.description" => "行为准则和安全问题的报告地址，会写入 CODE_OF_CONDUCT.md 和 SECURITY.md",
+        "license.file-differs" => "磁盘上的 {file} 是 {license}，与所选许可证不同。",
+        "license.cargo-differs" => "Cargo.toml 声明的是 {license}，与所选许可证不同。",
+        "license.cargo-unknown" => "Cargo.toml 声明的 {license} 不是可识别的许可证（{error}）。",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::default_fields;
+    use crate::preset::PRESETS;
+
+    #[test]
+    fn preset_prompts_are_translated() {
+        for lang in Lang::ALL {
+            let catalog = match lang {
+                Lang::En => continue,
+                Lang::Es => ui_es,
+                Lang::De => ui_de,
+                Lang::Fr => ui_fr,
+                Lang::Ja => ui_ja,
+                Lang::ZhCn => ui_zh_cn,
+            };
+            for preset in PRESETS {
+                for field in default_fields(Lang::En) {
+                    let key = format!("preset.{}.prompt.{}", preset.id, field.id);
+                    if ui_en(&key).is_some() {
+                        assert!(catalog(&key).is_some(), "{key} missing for {}", lang.code());
+                    }
+                }
+            }
+        }
+    }
+}
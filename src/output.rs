@@ -0,0 +1,72 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_FILE_NAME: &str = "README.md";
+
+/// What happened on disk after a successful write.
+pub struct WriteReport {
+    pub path: PathBuf,
+    pub backup: Option<PathBuf>,
+}
+
+/// Turns the user supplied output path into the file that will actually be
+/// written. Directories (existing ones, or paths ending in a separator) get
+/// `README.md` appended so `-o docs/` does the obvious thing.
+pub fn resolve_output_path(raw: &str) -> PathBuf {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return PathBuf::from(DEFAULT_FILE_NAME);
+    }
+    let path = PathBuf::from(raw);
+    if raw.ends_with('/') || raw.ends_with(std::path::MAIN_SEPARATOR) || path.is_dir() {
+        path.join(DEFAULT_FILE_NAME)
+    } else {
+        path
+    }
+}
+
+/// File name for a translated variant: `README.md` becomes `README.de.md`.
+pub fn variant_path(path: &Path, code: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem}.{code}.{}", ext.to_string_lossy()),
+        None => format!("{stem}.{code}"),
+    };
+    path.with_file_name(name)
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// Writes `contents` to `path`, creating missing parent directories and
+/// copying any existing file to `<name>.bak` first.
+pub fn write_readme(path: &Path, contents: &str) -> io::Result<WriteReport> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).map_err(|e| with_path(e, parent))?;
+        }
+    }
+
+    let backup = if path.is_file() {
+        let backup = backup_path(path);
+        fs::copy(path, &backup).map_err(|e| with_path(e, &backup))?;
+        Some(backup)
+    } else {
+        None
+    };
+
+    fs::write(path, contents).map_err(|e| with_path(e, path))?;
+
+    Ok(WriteReport {
+        path: path.to_path_buf(),
+        backup,
+    })
+}
+
+fn with_path(err: io::Error, path: &Path) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+}
@@ -0,0 +1,159 @@
+//! Project type presets.
+//!
+//! A preset picks the sections and badges that make sense for a kind of
+//! project and carries example values for its fields. Names, descriptions
+//! and field prompts live in the message catalog under `preset.<id>.*`.
+
+use crate::render::{Badge, Section};
+
+pub struct Preset {
+    pub id: &'static str,
+    pub sections: &'static [Section],
+    pub badges: &'static [Badge],
+    /// Info string for the Usage code block.
+    pub usage_lang: &'static str,
+    /// Example values keyed by field id. `{repo}` expands to the repository
+    /// slug, `{name}` to the repository name and `{ident}` to the name as a
+    /// Rust identifier.
+    pub examples: &'static [(&'static str, &'static str)],
+}
+
+impl Preset {
+    pub fn from_id(id: &str) -> Option<&'static Preset> {
+        PRESETS.iter().find(|preset| preset.id == id)
+    }
+
+    /// The example for `field`, expanded for the repository `repo`.
+    pub fn example(&self, field: &str, repo: &str) -> Option<String> {
+        let (_, example) = self.examples.iter().find(|(id, _)| *id == field)?;
+        let name = repo.rsplit('/').next().filter(|n| !n.is_empty()).unwrap_or("my-project");
+        let repo = if repo.is_empty() { "username/my-project" } else { repo };
+        Some(
+            example
+                .replace("{repo}", repo)
+                .replace("{name}", name)
+                .replace("{ident}", &name.replace(['-', '.'], "_")),
+        )
+    }
+}
+
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        id: "generic",
+        sections: &Section::DEFAULT,
+        badges: &Badge::DEFAULT,
+        usage_lang: "bash",
+        examples: &[],
+    },
+    Preset {
+        id: "cli",
+        sections: &[
+            Section::About,
+            Section::Features,
+            Section::BuiltWith,
+            Section::GettingStarted,
+            Section::Usage,
+            Section::Testing,
+            Section::Contributing,
+            Section::License,
+            Section::Authors,
+        ],
+        badges: &[Badge::Stars, Badge::Issues, Badge::License, Badge::Tech],
+        usage_lang: "bash",
+        examples: &[
+            ("installation", "Download a binary from https://github.com/{repo}/releases;Or build from source with cargo install --git https://github.com/{repo}"),
+            ("usage", "{name} --help"),
+            ("tests", "cargo test"),
+        ],
+    },
+    Preset {
+        id: "rust-lib",
+        sections: &[
+            Section::About,
+            Section::Features,
+            Section::GettingStarted,
+            Section::Usage,
+            Section::Api,
+            Section::Testing,
+            Section::Contributing,
+            Section::License,
+            Section::Authors,
+        ],
+        badges: &[Badge::CratesIo, Badge::DocsRs, Badge::License],
+        usage_lang: "rust",
+        examples: &[
+            ("prerequisites", "Rust stable (see rust-version in Cargo.toml)"),
+            ("installation", "cargo add {name}"),
+            ("usage", "use {ident}::*;"),
+            ("api", "Full API documentation: https://docs.rs/{name}"),
+            ("tests", "cargo test --all-features"),
+        ],
+    },
+    Preset {
+        id: "library",
+        sections: &[
+            Section::About,
+            Section::Features,
+            Section::GettingStarted,
+            Section::Usage,
+            Section::Api,
+            Section::Testing,
+            Section::Contributing,
+            Section::License,
+            Section::Authors,
+        ],
+        badges: &[Badge::Stars, Badge::Issues, Badge::License, Badge::Tech],
+        usage_lang: "bash",
+        examples: &[],
+    },
+    Preset {
+        id: "web-app",
+        sections: &[
+            Section::About,
+            Section::Screenshots,
+            Section::Features,
+            Section::BuiltWith,
+            Section::GettingStarted,
+            Section::Usage,
+            Section::Deployment,
+            Section::Testing,
+            Section::Contributing,
+            Section::License,
+            Section::Authors,
+        ],
+        badges: &Badge::DEFAULT,
+        usage_lang: "bash",
+        examples: &[
+            ("screenshots", "Home page|docs/screenshot.png"),
+            ("prerequisites", "Node.js 20+;npm"),
+            ("installation", "git clone https://github.com/{repo}.git;cd {name};npm install"),
+            ("usage", "npm run dev"),
+            ("deployment", "npm run build;Upload the dist/ folder to your static host"),
+            ("tests", "npm test"),
+        ],
+    },
+    Preset {
+        id: "api-service",
+        sections: &[
+            Section::About,
+            Section::Features,
+            Section::BuiltWith,
+            Section::GettingStarted,
+            Section::Usage,
+            Section::Api,
+            Section::Deployment,
+            Section::Testing,
+            Section::Contributing,
+            Section::License,
+            Section::Authors,
+        ],
+        badges: &Badge::DEFAULT,
+        usage_lang: "bash",
+        examples: &[
+            ("installation", "git clone https://github.com/{repo}.git;cd {name};cp .env.example .env"),
+            ("usage", "curl http://localhost:8080/health"),
+            ("api", "GET /health - service status"),
+            ("deployment", "docker build -t {name} .;docker run -p 8080:8080 --env-file .env {name}"),
+        ],
+    },
+];
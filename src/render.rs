@@ -0,0 +1,713 @@
+//! Turns the filled-in fields into README Markdown.
+//!
+//! The document is assembled from a template containing `{{placeholders}}`.
+//! The built-in template is [`DEFAULT_TEMPLATE`]; a custom one can use the
+//! same placeholders plus `{{section.<id>}}` and `{{field.<id>}}` to place
+//! individual pieces.
+
+use std::collections::BTreeMap;
+
+use crate::community::{self, CommunityFile};
+use crate::i18n::{self, Lang};
+use crate::license::{Expression, Operator};
+use crate::lint::slugify;
+use crate::field::Field;
+
+pub const DEFAULT_TEMPLATE: &str = "{{header}}\n\n{{toc}}\n\n{{sections}}\n\n{{footer}}\n";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Section {
+    About,
+    Screenshots,
+    Features,
+    BuiltWith,
+    GettingStarted,
+    Usage,
+    Api,
+    Deployment,
+    Testing,
+    Contributing,
+    /// A link to the CHANGELOG.md written next to the README.
+    Changelog,
+    License,
+    Authors,
+}
+
+impl Section {
+    pub const ALL: [Section; 13] = [
+        Section::About,
+        Section::Screenshots,
+        Section::Features,
+        Section::BuiltWith,
+        Section::GettingStarted,
+        Section::Usage,
+        Section::Api,
+        Section::Deployment,
+        Section::Testing,
+        Section::Contributing,
+        Section::Changelog,
+        Section::License,
+        Section::Authors,
+    ];
+
+    /// The sections rendered when nothing else is configured.
+    pub const DEFAULT: [Section; 10] = [
+        Section::About,
+        Section::Features,
+        Section::BuiltWith,
+        Section::GettingStarted,
+        Section::Usage,
+        Section::Api,
+        Section::Testing,
+        Section::Contributing,
+        Section::License,
+        Section::Authors,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Section::About => "about",
+            Section::Screenshots => "screenshots",
+            Section::Features => "features",
+            Section::BuiltWith => "built-with",
+            Section::GettingStarted => "getting-started",
+            Section::Usage => "usage",
+            Section::Api => "api-documentation",
+            Section::Deployment => "deployment",
+            Section::Testing => "testing",
+            Section::Contributing => "contributing",
+            Section::Changelog => "changelog",
+            Section::License => "license",
+            Section::Authors => "authors",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Section> {
+        Section::ALL.into_iter().find(|s| s.id() == id)
+    }
+
+    /// Ids of the fields this section is rendered from.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Section::About => &["description"],
+            Section::Screenshots => &["screenshots"],
+            Section::Features => &["features"],
+            Section::BuiltWith => &["technologies"],
+            Section::GettingStarted => &["prerequisites", "installation"],
+            Section::Usage => &["usage"],
+            Section::Api => &["api"],
+            Section::Deployment => &["deployment"],
+            Section::Testing => &["tests"],
+            Section::Contributing => &["contributing"],
+            Section::Changelog | Section::License => &[],
+            Section::Authors => &["authors"],
+        }
+    }
+
+    fn emoji(self) -> &'static str {
+        match self {
+            Section::About => "🔍",
+            Section::Screenshots => "📸",
+            Section::Features => "✨",
+            Section::BuiltWith => "🛠️",
+            Section::GettingStarted => "🚀",
+            Section::Usage => "💡",
+            Section::Api => "📚",
+            Section::Deployment => "📦",
+            Section::Testing => "🧪",
+            Section::Contributing => "🤝",
+            Section::Changelog => "📜",
+            Section::License => "📝",
+            Section::Authors => "👥",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Badge {
+    Stars,
+    Forks,
+    Issues,
+    License,
+    /// One badge per entry in the Technologies field.
+    Tech,
+    /// Latest version on crates.io, named after the repository.
+    CratesIo,
+    /// Documentation on docs.rs, named after the repository.
+    DocsRs,
+    /// Latest version on npm, named after the repository.
+    Npm,
+}
+
+impl Badge {
+    pub const ALL: [Badge; 8] = [
+        Badge::Stars,
+        Badge::Forks,
+        Badge::Issues,
+        Badge::License,
+        Badge::Tech,
+        Badge::CratesIo,
+        Badge::DocsRs,
+        Badge::Npm,
+    ];
+
+    /// The badges rendered when nothing else is configured.
+    pub const DEFAULT: [Badge; 5] = [
+        Badge::Stars,
+        Badge::Forks,
+        Badge::Issues,
+        Badge::License,
+        Badge::Tech,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Badge::Stars => "stars",
+            Badge::Forks => "forks",
+            Badge::Issues => "issues",
+            Badge::License => "license",
+            Badge::Tech => "tech",
+            Badge::CratesIo => "crates-io",
+            Badge::DocsRs => "docs-rs",
+            Badge::Npm => "npm",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Badge> {
+        Badge::ALL.into_iter().find(|b| b.id() == id)
+    }
+
+    /// The badge for `repo_name`; the License badge links the file
+    /// `license` is written to, or nothing when there are several.
+    fn markdown(self, repo_name: &str, license: &str) -> String {
+        let package = repo_name.rsplit('/').next().unwrap_or(repo_name);
+        match self {
+            Badge::Stars => format!("[![Stars](https://img.shields.io/github/stars/{repo_name}?style=flat-square)](https://github.com/{repo_name}/stargazers)"),
+            Badge::Forks => format!("[![Forks](https://img.shields.io/github/forks/{repo_name}?style=flat-square)](https://github.com/{repo_name}/network/members)"),
+            Badge::Issues => format!("[![Issues](https://img.shields.io/github/issues/{repo_name}?style=flat-square)](https://github.com/{repo_name}/issues)"),
+            Badge::License => {
+                let image = format!("![License](https://img.shields.io/github/license/{repo_name}?style=flat-square)");
+                let file = match Expression::parse(license) {
+                    Ok(expression) if expression.licenses.len() > 1 => None,
+                    Ok(expression) => expression.file_names().into_iter().next(),
+                    Err(_) => Some(String::from("LICENSE")),
+                };
+                match file {
+                    Some(file) => format!("[{image}](https://github.com/{repo_name}/blob/HEAD/{file})"),
+                    None => image,
+                }
+            }
+            Badge::Tech => String::new(),
+            Badge::CratesIo => format!("[![Crates.io](https://img.shields.io/crates/v/{package}?style=flat-square)](https://crates.io/crates/{package})"),
+            Badge::DocsRs => format!("[![docs.rs](https://img.shields.io/docsrs/{package}?style=flat-square)](https://docs.rs/{package})"),
+            Badge::Npm => format!("[![npm](https://img.shields.io/npm/v/{package}?style=flat-square)](https://www.npmjs.com/package/{package})"),
+        }
+    }
+}
+
+/// What goes in front of section headings.
+#[derive(Clone)]
+pub enum HeadingStyle {
+    Emoji,
+    Plain,
+    /// Icons keyed by section id (plus `toc`); sections without an entry
+    /// get no icon.
+    Custom(BTreeMap<String, String>),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum HeadingSyntax {
+    /// `## Title`
+    Atx,
+    /// `Title` underlined with `===`/`---`. Only levels 1 and 2 have a setext
+    /// form, deeper headings stay ATX.
+    Setext,
+}
+
+#[derive(Clone)]
+pub struct Options {
+    pub sections: Vec<Section>,
+    pub badges: Vec<Badge>,
+    pub heading_style: HeadingStyle,
+    pub heading_syntax: HeadingSyntax,
+    /// Info string of the Usage code block, e.g. `bash` or `rust`.
+    pub usage_lang: String,
+    /// Template text; `None` uses [`DEFAULT_TEMPLATE`].
+    pub template: Option<String>,
+    pub lang: Lang,
+    /// Every language variant being written with its file name, used for
+    /// the language switcher in the header. Empty for a single language.
+    pub translations: Vec<(Lang, String)>,
+    /// Community files written next to the README, linked from the
+    /// Contributing section.
+    pub community: Vec<CommunityFile>,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            sections: Section::DEFAULT.to_vec(),
+            badges: Badge::DEFAULT.to_vec(),
+            heading_style: HeadingStyle::Emoji,
+            heading_syntax: HeadingSyntax::Atx,
+            usage_lang: String::from("bash"),
+            template: None,
+            lang: Lang::En,
+            translations: Vec::new(),
+            community: Vec::new(),
+        }
+    }
+}
+
+fn value<'a>(fields: &'a [Field], id: &str) -> &'a str {
+    fields
+        .iter()
+        .find(|field| field.id == id)
+        .map_or("", |field| field.value.as_str())
+}
+
+fn bullets(value: &str, placeholder: &str) -> String {
+    if value.is_empty() {
+        return format!("- {placeholder}");
+    }
+    value
+        .split(';')
+        .map(|item| format!("- {}", item.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn numbered(value: &str, placeholder: &str) -> String {
+    if value.is_empty() {
+        return format!("1. {placeholder}");
+    }
+    value
+        .split(';')
+        .enumerate()
+        .map(|(i, step)| format!("{}. {}", i + 1, step.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// One image per `path` or `caption|path` item.
+fn screenshots(value: &str, placeholder: &str) -> String {
+    if value.is_empty() {
+        return placeholder.to_string();
+    }
+    value
+        .split(';')
+        .map(|item| match item.split_once('|') {
+            Some((caption, path)) => format!("![{}]({})", caption.trim(), path.trim()),
+            None => format!("![Screenshot]({})", item.trim()),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+struct Renderer<'a> {
+    fields: &'a [Field],
+    license: &'a str,
+    options: &'a Options,
+}
+
+impl Renderer<'_> {
+    fn field(&self, id: &str) -> &str {
+        value(self.fields, id)
+    }
+
+    fn tr(&self, key: &str) -> &'static str {
+        i18n::readme(self.options.lang, key)
+    }
+
+    fn icon(&self, key: &str, emoji: &str) -> Option<String> {
+        match &self.options.heading_style {
+            HeadingStyle::Emoji => Some(emoji.to_string()),
+            HeadingStyle::Plain => None,
+            HeadingStyle::Custom(icons) => icons.get(key).filter(|icon| !icon.is_empty()).cloned(),
+        }
+    }
+
+    /// Heading text for `title`, with the icon for `key` in front of it.
+    fn heading_text(&self, key: &str, emoji: &str, title: &str) -> String {
+        match self.icon(key, emoji) {
+            Some(icon) => format!("{icon} {title}"),
+            None => title.to_string(),
+        }
+    }
+
+    fn section_heading(&self, section: Section) -> String {
+        self.heading_text(section.id(), section.emoji(), self.tr(section.id()))
+    }
+
+    fn heading(&self, level: usize, text: &str) -> String {
+        match (self.options.heading_syntax, level) {
+            (HeadingSyntax::Setext, 1) => format!("{text}\n{}", "=".repeat(text.chars().count().max(3))),
+            (HeadingSyntax::Setext, 2) => format!("{text}\n{}", "-".repeat(text.chars().count().max(3))),
+            _ => format!("{} {text}", "#".repeat(level)),
+        }
+    }
+
+    fn header(&self) -> String {
+        let repo_name = self.field("repository");
+        let mut parts = vec![
+            String::from("<div align=\"center\">"),
+            self.heading(1, self.field("title")),
+            self.field("short_description").to_string(),
+        ];
+
+        if !repo_name.is_empty() {
+            let badges: Vec<String> = self
+                .options
+                .badges
+                .iter()
+                .filter(|&&badge| badge != Badge::Tech)
+                .map(|badge| badge.markdown(repo_name, self.license))
+                .collect();
+            if !badges.is_empty() {
+                parts.push(badges.join("\n"));
+            }
+        }
+
+        // With issue templates, open the matching form directly
+        let community = &self.options.community;
+        let issues = community::issues_url(repo_name, community);
+        let (bug, feature) = if community::on_gitlab(community) {
+            ("/new?issuable_template=Bug", "/new?issuable_template=Feature")
+        } else if community.contains(&CommunityFile::GitHubTemplates) {
+            ("/new?template=bug_report.md", "/new?template=feature_request.md")
+        } else {
+            ("", "")
+        };
+        // The documentation starts at the first section
+        let docs = match self.options.sections.first() {
+            Some(&section) => slugify(&self.section_heading(section)),
+            None => slugify(&self.heading_text("toc", "📋", self.tr("toc"))),
+        };
+        parts.push(format!(
+            "[{}](#{docs}) · [{}]({issues}{bug}) · [{}]({issues}{feature})",
+            self.tr("documentation"),
+            self.tr("report-bug"),
+            self.tr("request-feature"),
+        ));
+
+        if !self.options.translations.is_empty() {
+            parts.push(
+                self.options
+                    .translations
+                    .iter()
+                    .map(|(lang, file)| {
+                        if *lang == self.options.lang {
+                            format!("**{}**", lang.native_name())
+                        } else {
+                            format!("[{}]({file})", lang.native_name())
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" · "),
+            );
+        }
+
+        if self.options.badges.contains(&Badge::Tech) {
+            let tech_badges: Vec<String> = self
+                .field("technologies")
+                .split(';')
+                .filter(|t| !t.is_empty())
+                .map(|tech| {
+                    let tech = tech.trim().to_lowercase();
+                    format!("![{}](https://img.shields.io/badge/-{}-informational?style=flat-square&logo={}&logoColor=white)",
+                        tech, tech, tech)
+                })
+                .collect();
+            if tech_badges.is_empty() {
+                parts.push(self.tr("placeholder.tech-badges").to_string());
+            } else {
+                parts.push(tech_badges.join(" "));
+            }
+        }
+
+        parts.push(String::from("</div>"));
+        // Empty fields leave no blank lines behind
+        parts.retain(|part| !part.is_empty());
+        parts.join("\n\n")
+    }
+
+    fn toc(&self) -> String {
+        let title = self.heading_text("toc", "📋", self.tr("toc"));
+        let mut lines = vec![self.heading(2, &title), String::new()];
+        for &section in &self.options.sections {
+            // Link to the anchor GitHub derives from the heading as written,
+            // icon included, so the links keep working whatever the style.
+            lines.push(format!(
+                "- [{}](#{})",
+                self.tr(section.id()),
+                slugify(&self.section_heading(section))
+            ));
+            if section == Section::GettingStarted {
+                for key in ["prerequisites", "installation"] {
+                    lines.push(format!("  - [{}](#{})", self.tr(key), slugify(self.tr(key))));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn section(&self, section: Section) -> String {
+        let heading = self.heading(2, &self.section_heading(section));
+        let body = match section {
+            Section::About => self.field("description").to_string(),
+            Section::Features => bullets(self.field("features"), self.tr("placeholder.features")),
+            Section::BuiltWith => {
+                bullets(self.field("technologies"), self.tr("placeholder.technologies"))
+            }
+            Section::GettingStarted => format!(
+                "### {}\n\n{}\n\n### {}\n\n{}",
+                self.tr("prerequisites"),
+                bullets(self.field("prerequisites"), self.tr("placeholder.prerequisites")),
+                self.tr("installation"),
+                numbered(self.field("installation"), self.tr("placeholder.installation")),
+            ),
+            Section::Screenshots => screenshots(self.field("screenshots"), self.tr("placeholder.screenshots")),
+            Section::Usage => format!("```{}\n{}\n```", self.options.usage_lang, self.field("usage")),
+            Section::Api => format!("```text\n{}\n```", self.field("api")),
+            Section::Deployment => numbered(self.field("deployment"), self.tr("placeholder.deployment")),
+            Section::Testing => numbered(self.field("tests"), self.tr("placeholder.tests")),
+            Section::Contributing => {
+                let mut body = self.field("contributing").to_string();
+                let linked: Vec<_> = self
+                    .options
+                    .community
+                    .iter()
+                    .filter_map(|file| Some((file.id(), file.file_name()?)))
+                    .collect();
+                let items: Vec<String> = linked
+                    .into_iter()
+                    .map(|(id, name)| format!("- {}", self.tr(&format!("community.{id}")).replace("{file}", name)))
+                    .collect();
+                if !items.is_empty() {
+                    if !body.is_empty() {
+                        body.push_str("\n\n");
+                    }
+                    body.push_str(&items.join("\n"));
+                }
+                body
+            }
+            Section::Changelog => self.tr("changelog-sentence").to_string(),
+            Section::License => license_text(self.options.lang, self.license),
+            Section::Authors => bullets(self.field("authors"), self.tr("placeholder.authors")),
+        };
+        if body.is_empty() {
+            heading
+        } else {
+            format!("{heading}\n\n{body}")
+        }
+    }
+
+    fn footer(&self) -> String {
+        format!("---\n<div align=\"center\">\n{}\n</div>", self.tr("footer"))
+    }
+
+    fn render(&self) -> String {
+        let template = self.options.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+        let mut out = String::with_capacity(template.len() * 4);
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start..].find("}}") else {
+                break;
+            };
+            out.push_str(&rest[..start]);
+            let key = rest[start + 2..start + len].trim();
+            match self.placeholder(key) {
+                Some(text) => out.push_str(&text),
+                // Unknown placeholders are left alone so typos are visible.
+                None => out.push_str(&rest[start..start + len + 2]),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        out.push_str(rest);
+        // Exactly one trailing newline, whatever the template ends with
+        out.truncate(out.trim_end_matches('\n').len());
+        out.push('\n');
+        out
+    }
+
+    fn placeholder(&self, key: &str) -> Option<String> {
+        match key {
+            "header" => Some(self.header()),
+            "toc" => Some(self.toc()),
+            "sections" => Some(
+                self.options
+                    .sections
+                    .iter()
+                    .map(|&section| self.section(section))
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+            ),
+            "footer" => Some(self.footer()),
+            "license" => Some(match Expression::parse(self.license) {
+                Ok(expression) if expression.licenses.len() == 1 => expression.licenses[0].name.to_string(),
+                _ => self.license.to_string(),
+            }),
+            _ => {
+                if let Some(id) = key.strip_prefix("section.") {
+                    Section::from_id(id).map(|section| self.section(section))
+                } else if let Some(id) = key.strip_prefix("field.") {
+                    self.fields
+                        .iter()
+                        .find(|field| field.id == id)
+                        .map(|field| field.value.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// The License section's text for `license`, an SPDX expression or any
+/// other name. Several licenses are listed with the file each one is in.
+pub fn license_text(lang: Lang, license: &str) -> String {
+    let expression = match Expression::parse(license) {
+        Ok(expression) if expression.licenses.len() > 1 => expression,
+        Ok(expression) => {
+            return i18n::readme(lang, "license-sentence").replace("{license}", expression.licenses[0].name)
+        }
+        Err(_) => return i18n::readme(lang, "license-sentence").replace("{license}", license),
+    };
+    let key = match expression.operator {
+        Operator::Or => "license-sentence.any",
+        Operator::And => "license-sentence.all",
+    };
+    let mut text = i18n::readme(lang, key).to_string();
+    text.push('\n');
+    for (license, file) in expression.licenses.iter().zip(expression.file_names()) {
+        text.push_str(&format!("\n- {} ([{file}]({file}))", license.name));
+    }
+    text
+}
+
+pub fn render(fields: &[Field], license: &str, options: &Options) -> String {
+    Renderer {
+        fields,
+        license,
+        options,
+    }
+    .render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::default_fields;
+    use crate::lint;
+
+    fn filled(lang: Lang) -> Vec<Field> {
+        let mut fields = default_fields(lang);
+        for field in &mut fields {
+            field.value = match field.id {
+                "repository" => "octocat/tool",
+                "title" => "Tool",
+                "short_description" => "Does things.",
+                "screenshots" => "Main window|docs/main.png;docs/other.png",
+                "features" => "Fast;Small",
+                "technologies" => "Rust;Ratatui",
+                "prerequisites" => "Rust 1.70",
+                "installation" => "cargo install tool",
+                "usage" => "tool --help",
+                "api" => "fn run() -> Result<()>",
+                "deployment" => "cargo build --release",
+                "tests" => "cargo test",
+                "contact_email" => "me@example.com",
+                "authors" => "Me <me@example.com>",
+                _ => "Some text.",
+            }
+            .to_string();
+        }
+        fields
+    }
+
+    fn assert_clean(markdown: &str) {
+        let issues: Vec<String> = lint::lint(markdown).iter().map(|issue| issue.to_string()).collect();
+        assert!(issues.is_empty(), "{issues:#?}\n{markdown}");
+    }
+
+    #[test]
+    fn filled_readme_passes_lint() {
+        let options = Options {
+            sections: Section::ALL.to_vec(),
+            badges: Badge::ALL.to_vec(),
+            community: CommunityFile::ALL.to_vec(),
+            ..Options::default()
+        };
+        let markdown = render(&filled(Lang::En), "MIT", &options);
+        assert_clean(&markdown);
+        assert!(markdown.contains("- [Built With](#\u{fe0f}-built-with)"));
+    }
+
+    #[test]
+    fn every_style_and_language_passes_lint() {
+        for lang in Lang::ALL {
+            for heading_syntax in [HeadingSyntax::Atx, HeadingSyntax::Setext] {
+                for heading_style in [HeadingStyle::Emoji, HeadingStyle::Plain] {
+                    let options = Options {
+                        sections: Section::ALL.to_vec(),
+                        heading_style,
+                        heading_syntax,
+                        lang,
+                        translations: vec![(Lang::En, String::from("README.md")), (Lang::De, String::from("README.de.md"))],
+                        ..Options::default()
+                    };
+                    assert_clean(&render(&filled(lang), "MIT OR Apache-2.0", &options));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_fields_leave_no_blank_runs() {
+        let options = Options {
+            sections: Section::ALL.to_vec(),
+            community: CommunityFile::ALL.to_vec(),
+            ..Options::default()
+        };
+        let markdown = render(&default_fields(Lang::En), "MIT", &options);
+        let issues: Vec<_> = lint::lint(&markdown)
+            .into_iter()
+            .filter(|issue| issue.rule.starts_with("MD012") || issue.rule.starts_with("MD022"))
+            .map(|issue| issue.to_string())
+            .collect();
+        assert!(issues.is_empty(), "{issues:#?}\n{markdown}");
+    }
+
+    #[test]
+    fn license_badge_links_the_license_file() {
+        let badge = |license| Badge::License.markdown("octocat/tool", license);
+        assert!(badge("MIT").ends_with("](https://github.com/octocat/tool/blob/HEAD/LICENSE)"));
+        assert!(badge("Proprietary").ends_with("/blob/HEAD/LICENSE)"));
+        // Which of several files would be a guess, so there is no link
+        assert!(badge("MIT OR Apache-2.0").starts_with("![License]"));
+        assert!(badge("MIT/Apache-2.0").starts_with("![License]"));
+    }
+
+    #[test]
+    fn issue_links_follow_the_chosen_templates() {
+        let header = |community: Vec<CommunityFile>| {
+            let options = Options { badges: Vec::new(), community, ..Options::default() };
+            render(&filled(Lang::En), "MIT", &options)
+        };
+        assert!(header(vec![]).contains("(https://github.com/octocat/tool/issues)"));
+        assert!(header(CommunityFile::ALL.to_vec()).contains("(https://github.com/octocat/tool/issues/new?template=bug_report.md)"));
+        let gitlab = header(vec![CommunityFile::GitLabTemplates]);
+        assert!(gitlab.contains("(https://gitlab.com/octocat/tool/-/issues/new?issuable_template=Bug)"));
+        assert!(gitlab.contains("(https://gitlab.com/octocat/tool/-/issues/new?issuable_template=Feature)"));
+        assert!(!gitlab.contains("github.com/octocat/tool/issues"));
+    }
+
+    #[test]
+    fn output_ends_with_one_newline() {
+        let options = Options {
+            template: Some(String::from("{{field.title}}\n\n\n")),
+            ..Options::default()
+        };
+        assert_eq!(render(&filled(Lang::En), "MIT", &options), "Tool\n");
+    }
+}
@@ -0,0 +1,65 @@
+//! Unfinished work saved between runs.
+//!
+//! Quitting before the README is written keeps the field values, choices
+//! and edit history in `$XDG_STATE_HOME/readmegen/session.toml`
+//! (`~/.local/state/readmegen/session.toml` when the variable is unset),
+//! and the next run in the same directory picks up from there. There is one
+//! saved session at a time.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::history::History;
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// Directory the session was started in; other directories ignore it.
+    pub dir: PathBuf,
+    pub preset: Option<String>,
+    pub license: Option<String>,
+    /// Wizard page to resume on.
+    pub page: usize,
+    /// Values keyed by field id.
+    pub fields: BTreeMap<String, String>,
+    pub history: History,
+}
+
+impl Session {
+    /// The saved session for `dir`, if there is a readable one.
+    pub fn load(dir: &Path) -> Option<Session> {
+        let text = fs::read_to_string(file()?).ok()?;
+        let session: Session = toml::from_str(&text).ok()?;
+        (session.dir == dir).then_some(session)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = file().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no state directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let text = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, text)
+    }
+
+    /// Removes the saved session if it belongs to `dir`.
+    pub fn clear(dir: &Path) -> io::Result<()> {
+        match file() {
+            Some(path) if Session::load(dir).is_some() => fs::remove_file(path),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn file() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_STATE_HOME").filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".local").join("state"),
+    };
+    Some(base.join("readmegen").join("session.toml"))
+}
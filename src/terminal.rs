@@ -0,0 +1,103 @@
+//! Raw mode and the alternate screen, undone however the TUI ends.
+//!
+//! [`Guard`] restores the terminal when dropped, which covers returns,
+//! `?` and unwinding panics. Release builds abort on panic without
+//! unwinding, so [`Guard::new`] also installs a panic hook that restores
+//! the terminal before the panic message is printed. Dropping the guard
+//! puts the previous hook back.
+
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+use std::panic;
+use std::sync::Arc;
+use std::thread;
+
+use crossterm::{
+    cursor::Show,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{backend::CrosstermBackend, Terminal};
+
+pub struct Guard<W: Write> {
+    terminal: Terminal<CrosstermBackend<W>>,
+    /// Reinstates the panic hook [`Guard::new`] replaced.
+    reinstate: Option<Box<dyn FnOnce()>>,
+}
+
+impl<W: Write + 'static> Guard<W> {
+    /// Takes over the terminal on the stream `open` returns. `open` is
+    /// called again from the panic hook, which cannot borrow the guard.
+    pub fn new(open: fn() -> W) -> io::Result<Guard<W>> {
+        let previous = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            let _ = restore(&mut open());
+            hook(info);
+        }));
+        let reinstate = move || {
+            // Dropping our hook leaves `previous` with a single owner
+            drop(panic::take_hook());
+            if let Ok(previous) = Arc::try_unwrap(previous) {
+                panic::set_hook(previous);
+            }
+        };
+
+        if let Err(err) = enable_raw_mode() {
+            reinstate();
+            return Err(err);
+        }
+        let mut out = open();
+        if let Err(err) = execute!(out, EnterAlternateScreen, EnableMouseCapture) {
+            let _ = restore(&mut out);
+            reinstate();
+            return Err(err);
+        }
+        let terminal = match Terminal::new(CrosstermBackend::new(out)) {
+            Ok(terminal) => terminal,
+            Err(err) => {
+                let _ = restore(&mut open());
+                reinstate();
+                return Err(err);
+            }
+        };
+        Ok(Guard {
+            terminal,
+            reinstate: Some(Box::new(reinstate)),
+        })
+    }
+}
+
+impl<W: Write> Drop for Guard<W> {
+    fn drop(&mut self) {
+        let _ = restore(self.terminal.backend_mut());
+        // Later panics happen outside the TUI and need no restoring. The
+        // hook cannot be changed while unwinding, when it does not matter.
+        if !thread::panicking() {
+            if let Some(reinstate) = self.reinstate.take() {
+                reinstate();
+            }
+        }
+    }
+}
+
+impl<W: Write> Deref for Guard<W> {
+    type Target = Terminal<CrosstermBackend<W>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.terminal
+    }
+}
+
+impl<W: Write> DerefMut for Guard<W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.terminal
+    }
+}
+
+/// Leaves the alternate screen and raw mode. Safe to call more than once.
+fn restore<W: Write>(out: &mut W) -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(out, LeaveAlternateScreen, DisableMouseCapture, Show)
+}
@@ -0,0 +1,125 @@
+//! Color themes for the TUI.
+//!
+//! Widgets never pick colors themselves; they ask the theme for the style
+//! of a role such as "selected item" or "validation error". Without a
+//! configured theme, a non-empty `NO_COLOR` environment variable selects
+//! `mono`, which conveys the same roles with bold, dim, underline and
+//! reverse video only.
+
+use std::env;
+
+use ratatui::style::{Color, Modifier, Style};
+
+pub const PRESETS: [&str; 4] = ["dark", "light", "high-contrast", "mono"];
+
+#[derive(Clone, Copy)]
+pub struct Theme {
+    /// Borders of every box.
+    pub border: Style,
+    /// Body text.
+    pub text: Style,
+    /// Text that is typed into, while it is.
+    pub editing: Style,
+    /// The highlighted entry of a list.
+    pub selected: Style,
+    /// Titles and key names.
+    pub strong: Style,
+    /// Placeholders, examples and other secondary text.
+    pub muted: Style,
+    pub error: Style,
+    pub warning: Style,
+    /// Step dots: the current step, finished steps, steps not reached yet.
+    pub step_current: Style,
+    pub step_done: Style,
+    pub step_todo: Style,
+    pub diff_hunk: Style,
+    pub diff_added: Style,
+    pub diff_removed: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        let name = match env::var_os("NO_COLOR") {
+            Some(value) if !value.is_empty() => "mono",
+            _ => "dark",
+        };
+        Theme::preset(name).expect("built-in theme")
+    }
+}
+
+impl Theme {
+    /// One of [`PRESETS`].
+    pub fn preset(name: &str) -> Option<Theme> {
+        let plain = Style::default();
+        let bold = plain.add_modifier(Modifier::BOLD);
+        let fg = |color| plain.fg(color);
+        Some(match name {
+            "dark" => Theme {
+                border: plain,
+                text: plain,
+                editing: fg(Color::Yellow),
+                selected: fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                strong: bold,
+                muted: fg(Color::DarkGray),
+                error: fg(Color::Red),
+                warning: fg(Color::Yellow),
+                step_current: fg(Color::Green).add_modifier(Modifier::BOLD),
+                step_done: fg(Color::White),
+                step_todo: fg(Color::DarkGray),
+                diff_hunk: fg(Color::Cyan),
+                diff_added: fg(Color::Green),
+                diff_removed: fg(Color::Red),
+            },
+            // Yellow and white vanish on a light background.
+            "light" => Theme {
+                border: fg(Color::DarkGray),
+                text: plain,
+                editing: fg(Color::Blue),
+                selected: fg(Color::Blue).add_modifier(Modifier::BOLD),
+                strong: bold,
+                muted: fg(Color::DarkGray),
+                error: fg(Color::Red),
+                warning: fg(Color::Magenta),
+                step_current: fg(Color::Green).add_modifier(Modifier::BOLD),
+                step_done: fg(Color::Black),
+                step_todo: fg(Color::Gray),
+                diff_hunk: fg(Color::Blue),
+                diff_added: fg(Color::Green),
+                diff_removed: fg(Color::Red),
+            },
+            "high-contrast" => Theme {
+                border: fg(Color::White).add_modifier(Modifier::BOLD),
+                text: fg(Color::White),
+                editing: fg(Color::LightYellow).add_modifier(Modifier::BOLD),
+                selected: fg(Color::Black).bg(Color::LightYellow).add_modifier(Modifier::BOLD),
+                strong: fg(Color::White).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                muted: fg(Color::Gray),
+                error: fg(Color::LightRed).add_modifier(Modifier::BOLD),
+                warning: fg(Color::LightYellow).add_modifier(Modifier::BOLD),
+                step_current: fg(Color::LightGreen).add_modifier(Modifier::BOLD),
+                step_done: fg(Color::White).add_modifier(Modifier::BOLD),
+                step_todo: fg(Color::Gray),
+                diff_hunk: fg(Color::LightCyan).add_modifier(Modifier::BOLD),
+                diff_added: fg(Color::LightGreen).add_modifier(Modifier::BOLD),
+                diff_removed: fg(Color::LightRed).add_modifier(Modifier::BOLD),
+            },
+            "mono" => Theme {
+                border: plain,
+                text: plain,
+                editing: bold,
+                selected: bold.add_modifier(Modifier::REVERSED),
+                strong: bold,
+                muted: plain.add_modifier(Modifier::DIM),
+                error: bold.add_modifier(Modifier::UNDERLINED),
+                warning: bold,
+                step_current: bold.add_modifier(Modifier::REVERSED),
+                step_done: plain,
+                step_todo: plain.add_modifier(Modifier::DIM),
+                diff_hunk: bold,
+                diff_added: bold,
+                diff_removed: plain.add_modifier(Modifier::DIM),
+            },
+            _ => return None,
+        })
+    }
+}
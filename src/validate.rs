@@ -0,0 +1,189 @@
+//! Input validation for README fields.
+
+#[derive(Clone, Copy)]
+pub enum Validator {
+    /// `owner/repo` as used in GitHub URLs.
+    RepoSlug,
+    /// Semicolon separated list without empty items.
+    List,
+    /// A list of authors, each optionally carrying `<email>` and `(url)`.
+    Authors,
+    /// A single email address.
+    Email,
+}
+
+impl Validator {
+    /// Checks a non-empty field value. Empty values are handled by the
+    /// "all fields filled" rule, not here.
+    pub fn check(self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        match self {
+            Validator::RepoSlug => check_repo_slug(value.trim()),
+            Validator::List => check_list(value).map(|_| ()),
+            Validator::Authors => {
+                for author in check_list(value)? {
+                    check_author(author)?;
+                }
+                Ok(())
+            }
+            Validator::Email => {
+                if is_email(value.trim()) {
+                    Ok(())
+                } else {
+                    Err(format!("'{}' is not a valid email address", value.trim()))
+                }
+            }
+        }
+    }
+}
+
+fn is_slug_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'
+}
+
+fn check_repo_slug(value: &str) -> Result<(), String> {
+    let (owner, repo) = value
+        .split_once('/')
+        .ok_or_else(|| String::from("expected owner/repo"))?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return Err(String::from("expected owner/repo"));
+    }
+    if !owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') || owner.starts_with('-') {
+        return Err(format!("'{owner}' is not a valid owner name"));
+    }
+    if !repo.chars().all(is_slug_char) || repo == "." || repo == ".." {
+        return Err(format!("'{repo}' is not a valid repository name"));
+    }
+    Ok(())
+}
+
+fn check_list(value: &str) -> Result<Vec<&str>, String> {
+    let items: Vec<&str> = value.split(';').map(str::trim).collect();
+    match items.iter().position(|item| item.is_empty()) {
+        Some(i) => Err(format!("item {} is empty (stray ';'?)", i + 1)),
+        None => Ok(items),
+    }
+}
+
+/// `Name`, `Name <email>`, `Name (url)` or `Name <email> (url)`.
+fn check_author(author: &str) -> Result<(), String> {
+    if let Some(start) = author.find('<') {
+        let end = author[start..]
+            .find('>')
+            .ok_or_else(|| format!("unclosed '<' in '{author}'"))?;
+        let email = &author[start + 1..start + end];
+        if !is_email(email) {
+            return Err(format!("'{email}' is not a valid email address"));
+        }
+    }
+    if let Some(start) = author.find('(') {
+        let end = author[start..]
+            .find(')')
+            .ok_or_else(|| format!("unclosed '(' in '{author}'"))?;
+        let url = &author[start + 1..start + end];
+        if !is_url(url) {
+            return Err(format!("'{url}' is not a valid http(s) URL"));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !local.contains(char::is_whitespace)
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '.')
+}
+
+pub fn is_url(value: &str) -> bool {
+    let Some(rest) = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+    !host.is_empty()
+        && !value.contains(char::is_whitespace)
+        && host
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repo_slugs() {
+        assert!(check_repo_slug("octocat/hello-world").is_ok());
+        assert!(check_repo_slug("octo-cat/hello_world.rs").is_ok());
+        assert!(check_repo_slug("octocat").is_err());
+        assert!(check_repo_slug("owner/").is_err());
+        assert!(check_repo_slug("/repo").is_err());
+        assert!(check_repo_slug("owner/repo/extra").is_err());
+        assert_eq!(check_repo_slug("-x/y"), Err(String::from("'-x' is not a valid owner name")));
+        assert!(check_repo_slug("own_er/repo").is_err());
+        assert!(check_repo_slug("owner/..").is_err());
+        assert!(check_repo_slug("owner/re po").is_err());
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(check_list("a; b ;c"), Ok(vec!["a", "b", "c"]));
+        assert_eq!(check_list("a;;b"), Err(String::from("item 2 is empty (stray ';'?)")));
+        assert!(check_list("a;").is_err());
+        assert!(check_list(";a").is_err());
+        assert!(check_list("a; ;b").is_err());
+    }
+
+    #[test]
+    fn authors() {
+        assert!(check_author("Name").is_ok());
+        assert!(check_author("Name <me@example.com>").is_ok());
+        assert!(check_author("Name (https://example.com)").is_ok());
+        assert!(check_author("Name <me@example.com> (https://example.com/me)").is_ok());
+        assert_eq!(check_author("Name <bad>"), Err(String::from("'bad' is not a valid email address")));
+        assert_eq!(check_author("Name (ftp://x)"), Err(String::from("'ftp://x' is not a valid http(s) URL")));
+        assert!(check_author("Name <me@example.com").is_err());
+        assert!(check_author("Name (https://example.com").is_err());
+        assert!(Validator::Authors.check("Ann <ann@example.com>;;Bob").is_err());
+    }
+
+    #[test]
+    fn emails() {
+        assert!(is_email("me@example.com"));
+        assert!(is_email("first.last+tag@sub.example.co"));
+        assert!(!is_email("me"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("me@localhost"));
+        assert!(!is_email("me@.example.com"));
+        assert!(!is_email("me@example.com."));
+        assert!(!is_email("m e@example.com"));
+        assert!(!is_email("me@exa mple.com"));
+    }
+
+    #[test]
+    fn urls() {
+        assert!(is_url("https://example.com"));
+        assert!(is_url("http://example.com:8080/path?q=1#top"));
+        assert!(is_url("https://user@example.com/"));
+        assert!(!is_url("ftp://x"));
+        assert!(!is_url("example.com"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("https:///path"));
+        assert!(!is_url("https://exa mple.com"));
+        assert!(!is_url("https://example.com/a b"));
+    }
+}